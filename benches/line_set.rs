@@ -0,0 +1,49 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use curvy::geometry::line::{Line, LineIntersection};
+use curvy::geometry::line_set::LineSet;
+use curvy::geometry::{Intersects, Point};
+
+// Short segments scattered over a 1000 by 1000 square, by a simple deterministic
+// pseudorandom sequence.
+fn scattered_lines(n: usize) -> Vec<Line<f64>> {
+    let mut state: u64 = 12345;
+    let mut next = move || {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (state >> 11) as f64 / (1u64 << 53) as f64
+    };
+    (0..n)
+        .map(|_| {
+            let x = next() * 1000.0;
+            let y = next() * 1000.0;
+            let dx = next() * 10.0 - 5.0;
+            let dy = next() * 10.0 - 5.0;
+            Line::new(Point::new(x, y), Point::new(x + dx, y + dy + 0.1)).unwrap()
+        })
+        .collect()
+}
+
+fn intersect_many(c: &mut Criterion) {
+    let lines = scattered_lines(20000);
+    let line_set = LineSet::new(lines.clone());
+    let query = Line::new(Point::new(400.0, 300.0), Point::new(450.0, 320.0)).unwrap();
+
+    c.bench_function("naive intersect 20k lines", |b| {
+        b.iter(|| {
+            let query = black_box(&query);
+            lines
+                .iter()
+                .filter_map(|line| match query.intersect(line) {
+                    | LineIntersection::OnePoint(point) => Some(point),
+                    | _ => None,
+                })
+                .collect::<Vec<_>>()
+        })
+    });
+    c.bench_function("LineSet intersect 20k lines", |b| {
+        b.iter(|| line_set.intersect_line(black_box(&query)))
+    });
+}
+
+criterion_group!(benches, intersect_many);
+criterion_main!(benches);
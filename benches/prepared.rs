@@ -0,0 +1,57 @@
+use std::f64::consts::PI;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use curvy::geometry::poly::Polygon;
+use curvy::geometry::prepared::PreparedPolygon;
+use curvy::geometry::Point;
+
+// Regular polygon of n points around the origin, with a radius of 1.
+fn regular_polygon(n: usize) -> Polygon<f64> {
+    Polygon::new(
+        (0..n)
+            .map(|k| {
+                let angle = 2.0 * PI * k as f64 / n as f64;
+                Point::new(angle.cos(), angle.sin())
+            })
+            .collect(),
+    )
+    .unwrap()
+}
+
+// Points on a grid over the square from -1.5 to 1.5, so some fall outside the bounds.
+fn grid_points(n: usize) -> Vec<Point<f64>> {
+    (0..n * n)
+        .map(|i| {
+            let x = (i % n) as f64 / n as f64 * 3.0 - 1.5;
+            let y = (i / n) as f64 / n as f64 * 3.0 - 1.5;
+            Point::new(x, y)
+        })
+        .collect()
+}
+
+fn contains(c: &mut Criterion) {
+    let polygon = regular_polygon(100);
+    let prepared = PreparedPolygon::new(&polygon);
+    let points = grid_points(100);
+
+    c.bench_function("Polygon contains 10k points, 100-gon", |b| {
+        b.iter(|| {
+            points
+                .iter()
+                .filter(|&&point| polygon.contains(black_box(point)))
+                .count()
+        })
+    });
+    c.bench_function("PreparedPolygon contains 10k points, 100-gon", |b| {
+        b.iter(|| {
+            points
+                .iter()
+                .filter(|&&point| prepared.contains(black_box(point)))
+                .count()
+        })
+    });
+}
+
+criterion_group!(benches, contains);
+criterion_main!(benches);
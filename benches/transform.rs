@@ -0,0 +1,64 @@
+use std::f64::consts::PI;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use curvy::geometry::transform::Affine;
+use curvy::geometry::{Angle, Delta, Point};
+
+fn grid_points(n: usize) -> Vec<Point<f64>> {
+    (0..n)
+        .map(|i| Point::new((i % 1000) as f64, (i / 1000) as f64))
+        .collect()
+}
+
+fn rotate(c: &mut Criterion) {
+    let angle = Angle::new(PI / 7.0);
+    let deltas: Vec<Delta<f64>> = grid_points(100_000)
+        .into_iter()
+        .map(|point| point - Point::origin())
+        .collect();
+
+    c.bench_function("rotate 100k deltas one by one", |b| {
+        b.iter(|| {
+            deltas
+                .iter()
+                .map(|delta| delta.rotate(black_box(angle)))
+                .collect::<Vec<_>>()
+        })
+    });
+    c.bench_function("rotate 100k deltas in batch", |b| {
+        b.iter(|| {
+            let mut rotated = deltas.clone();
+            Delta::rotate_all(&mut rotated, black_box(angle));
+            rotated
+        })
+    });
+}
+
+fn transform(c: &mut Criterion) {
+    let center = Point::new(500.0, 50.0);
+    let angle = Angle::new(PI / 7.0);
+    let transformation = Affine::translation(Point::origin() - center)
+        .then(Affine::rotation(angle))
+        .then(Affine::translation(center - Point::origin()));
+    let points = grid_points(100_000);
+
+    c.bench_function("rotate 100k points about a center one by one", |b| {
+        b.iter(|| {
+            points
+                .iter()
+                .map(|point| point.rotate_about(black_box(center), black_box(angle)))
+                .collect::<Vec<_>>()
+        })
+    });
+    c.bench_function("transform 100k points in batch", |b| {
+        b.iter(|| {
+            let mut transformed = points.clone();
+            Point::transform_all(&mut transformed, black_box(&transformation));
+            transformed
+        })
+    });
+}
+
+criterion_group!(benches, rotate, transform);
+criterion_main!(benches);
@@ -4,18 +4,30 @@ use std::ops::{Add, Neg, Sub};
 
 use decorum::{Finite, Real};
 use derive_more::{Add};
-use num_traits::Zero;
+use num_traits::{Signed, ToPrimitive, Zero};
 
 use crate::geometry::*;
 
 // Angle of value 0 to 2PI. Use this unless you need to know the difference between
-// +180deg and -180deg for instance.
+// +180deg and -180deg for instance. Deserialized angles are normalized into that range.
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(from = "AngleDiff<T>")
+)]
 #[derive(Clone, Copy, Debug)]
-pub struct Angle<T: Value>(pub Finite<T>);
+pub struct Angle<T: Value>(
+    #[cfg_attr(feature = "serde", serde(with = "crate::geometry::serde_finite"))]
+    pub Finite<T>,
+);
 
 // Angular difference of value -2PI to 2PI
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[derive(Add, Clone, Copy, Debug)]
-pub struct AngleDiff<T: Value>(pub Finite<T>);
+pub struct AngleDiff<T: Value>(
+    #[cfg_attr(feature = "serde", serde(with = "crate::geometry::serde_finite"))]
+    pub Finite<T>,
+);
 
 pub trait Angular<T: Value> {
     fn radians(self) -> Finite<T>;
@@ -24,7 +36,7 @@ pub trait Angular<T: Value> {
 impl<T: Value> Neg for Angle<T> {
     type Output = Self;
     fn neg(self) -> Self {
-        let two_pi = Finite::<T>::from_inner(T::from_f64(2.0 * f64::PI).unwrap());
+        let two_pi = T::tau();
         Angle(two_pi - self.0)
     }
 }
@@ -50,7 +62,7 @@ impl<T: Value> Angular<T> for AngleDiff<T> {
 
 impl<T: Value> PartialEq for Angle<T> {
     fn eq(&self, other: &Self) -> bool {
-        let two_pi = Finite::<T>::from_inner(T::from_f64(2.0 * f64::PI).unwrap());
+        let two_pi = T::tau();
         return self.0 % two_pi == other.0 % two_pi;
     }
 }
@@ -59,8 +71,8 @@ impl<T: Value> Eq for Angle<T> {}
 impl<T: Value> Add<AngleDiff<T>> for Angle<T> {
     type Output = Angle<T>;
     fn add(self, diff: AngleDiff<T>) -> Self::Output {
-        let two_pi = Finite::<T>::from_inner(T::from_f64(2.0 * f64::PI).unwrap());
-        Angle((diff.0 + self.0) % two_pi)
+        // Wraps negative sums back into 0 to 2PI, as the remainder alone would not.
+        Angle::from(AngleDiff(self.0 + diff.0))
     }
 }
 
@@ -69,15 +81,15 @@ impl<T: Value> Sub for Angle<T> {
     // Angular difference based on shortest direction. Thus the result is always
     // between -PI and PI (-180deg and 180deg).
     fn sub(self, other: Self) -> Self::Output {
-        let two_pi = Finite::<T>::from_inner(T::from_f64(2.0 * f64::PI).unwrap());
-        let pi = Finite::<T>::from_inner(T::from_f64(f64::PI).unwrap());
+        let two_pi = T::tau();
+        let pi = T::pi();
         AngleDiff(((self.0 - other.0 + pi) % two_pi) - pi)
     }
 }
 
 impl<T: Value> From<Delta<T>> for Angle<T> {
     fn from(item: Delta<T>) -> Self {
-        let two_pi = Finite::<T>::from_inner(T::from_f64(2.0 * f64::PI).unwrap());
+        let two_pi = T::tau();
         Angle((item.dy.atan2(item.dx) + two_pi) % two_pi)
     }
 }
@@ -89,6 +101,33 @@ impl<T: Value> std::ops::Mul<Finite<T>> for Angle<T> {
     }
 }
 
+impl<T: Value> std::ops::Mul<Finite<T>> for AngleDiff<T> {
+    type Output = AngleDiff<T>;
+    fn mul(self, value: Finite<T>) -> Self::Output {
+        AngleDiff(self.0 * value)
+    }
+}
+
+impl<T: Value> std::ops::Mul<AngleDiff<T>> for Finite<T> {
+    type Output = AngleDiff<T>;
+    fn mul(self, diff: AngleDiff<T>) -> Self::Output {
+        AngleDiff(self * diff.0)
+    }
+}
+
+impl<T: Value> std::ops::Div<Finite<T>> for AngleDiff<T> {
+    type Output = AngleDiff<T>;
+    fn div(self, value: Finite<T>) -> Self::Output {
+        AngleDiff(self.0 / value)
+    }
+}
+
+impl<T: Value> AngleDiff<T> {
+    pub fn half(self) -> Self {
+        self / T::two()
+    }
+}
+
 impl<T: Value> fmt::Display for Angle<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let value = self.radians();
@@ -97,6 +136,31 @@ impl<T: Value> fmt::Display for Angle<T> {
     }
 }
 
+// Angle ordered by its radians from 0 to 2PI, for sorting directions deterministically.
+// Angle itself isn't ordered, since no angle is really less than another.
+#[derive(Clone, Copy, Debug)]
+pub struct OrderedAngle<T: Value>(pub Angle<T>);
+
+impl<T: Value> PartialEq for OrderedAngle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.radian_key() == other.0.radian_key()
+    }
+}
+
+impl<T: Value> Eq for OrderedAngle<T> {}
+
+impl<T: Value> PartialOrd for OrderedAngle<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Value> Ord for OrderedAngle<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.radian_key().cmp(&other.0.radian_key())
+    }
+}
+
 #[derive(Debug, Eq, PartialEq)]
 pub enum Direction {
     None,
@@ -108,7 +172,7 @@ impl<T: Value> Angle<T> {
     // Angle::new() will panic if theta is not finite
     pub fn new(theta: T) -> Self {
         let theta = Finite::<T>::from_inner(theta);
-        let two_pi = Finite::<T>::from_inner(T::from_f64(2.0 * f64::PI).unwrap());
+        let two_pi = T::tau();
         assert_ge!(theta, Finite::<T>::zero());
         assert_lt!(theta, two_pi);
         Angle(theta)
@@ -116,7 +180,7 @@ impl<T: Value> Angle<T> {
 
     pub fn direction(self, other: Angle<T>) -> Direction {
         // Direction of shortest rotation from this angle to another.
-        let two_pi = Finite::<T>::from_inner(T::from_f64(2.0 * f64::PI).unwrap());
+        let two_pi = T::tau();
         match ((self.0 - other.0) % two_pi).cmp(&Finite::<T>::PI) {
             | Ordering::Equal => Direction::None,
             | Ordering::Greater => Direction::Counterclockwise,
@@ -124,6 +188,54 @@ impl<T: Value> Angle<T> {
         }
     }
 
+    // Degrees, arcminutes and arcseconds, such as for surveying. Seconds within rounding
+    // error of a whole number are rounded to it, so that 1.5deg is exactly 1deg 30' 0".
+    // Degrees are always below 360.
+    pub fn to_dms(self) -> (i32, u32, Finite<T>) {
+        let seconds_per_radian =
+            Finite::<T>::from_inner(T::from_f64(648000.0 / f64::PI).unwrap());
+        let sixty = Finite::<T>::from_inner(T::from_f64(60.0).unwrap());
+        let mut total_seconds = self.0 * seconds_per_radian;
+        let nearest = total_seconds.round();
+        if (total_seconds - nearest).abs().into_inner() < T::EPSILON.sqrt() {
+            total_seconds = nearest;
+        }
+        let total_minutes = (total_seconds / sixty).floor();
+        let degrees = (total_minutes / sixty).floor();
+        let minutes = total_minutes - degrees * sixty;
+        // Rounding an angle just below a full turn up gives 360deg, which wraps to 0.
+        let degrees = degrees % Finite::<T>::from_inner(T::from_f64(360.0).unwrap());
+        let seconds = total_seconds - total_minutes * sixty;
+        (degrees.to_i32().unwrap(), minutes.to_u32().unwrap(), seconds)
+    }
+
+    // Angle from degrees, arcminutes and arcseconds. The sign of the degrees applies to
+    // the whole angle, so -1deg 30' is -1.5deg, or 358.5deg.
+    pub fn from_dms(degrees: i32, minutes: u32, seconds: Finite<T>) -> Self {
+        let radians_per_second =
+            Finite::<T>::from_inner(T::from_f64(f64::PI / 648000.0).unwrap());
+        let sixty = Finite::<T>::from_inner(T::from_f64(60.0).unwrap());
+        let magnitude = Finite::<T>::from_inner(T::from_u32(degrees.unsigned_abs()).unwrap())
+            * sixty
+            * sixty
+            + Finite::<T>::from_inner(T::from_u32(minutes).unwrap()) * sixty
+            + seconds;
+        let total_seconds = if degrees < 0 { -magnitude } else { magnitude };
+        Angle::from(AngleDiff(total_seconds * radians_per_second))
+    }
+
+    // Formatted as degrees, arcminutes and arcseconds, like 1°30'0".
+    pub fn format_dms(self) -> String {
+        let (degrees, minutes, seconds) = self.to_dms();
+        format!("{}°{}'{}\"", degrees, minutes, seconds)
+    }
+
+    // Radians from 0 to 2PI, such as for sorting. This ignores that angles wrap around, so
+    // it's no use for asking whether one angle is ahead of another.
+    pub fn radian_key(self) -> Finite<T> {
+        self.0 % T::tau()
+    }
+
     // Returns true if self is between start and stop by the shortest path.
     pub fn between(self, start: Angle<T>, stop: Angle<T>) -> bool {
         start.direction(self) == start.direction(stop)
@@ -144,10 +256,10 @@ impl<T: Value> Angle<T> {
 
 impl<T: Value> From<AngleDiff<T>> for Angle<T> {
     fn from(diff: AngleDiff<T>) -> Self {
-        let two_pi = Finite::<T>::from_inner(T::from_f64(2.0 * f64::PI).unwrap());
-        // Add two_pi first because modulus doesn't work as expected for negative
-        // numbers.
-        Angle((diff.0 + two_pi) % two_pi)
+        let two_pi = T::tau();
+        // The remainder keeps the sign of diff, so wrap negative remainders around
+        // once more.
+        Angle(((diff.0 % two_pi) + two_pi) % two_pi)
     }
 }
 
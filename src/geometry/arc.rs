@@ -1,27 +1,35 @@
 use decorum::{Finite, Real};
-use num_traits::{One, Zero};
+use num_traits::{One, Signed, Zero};
 
 use crate::geometry::error::*;
+use crate::geometry::bounds::Rect;
 use crate::geometry::line::{Line, LineIntersection};
+use crate::geometry::path::coincident;
 use crate::geometry::*;
 use crate::geometry::{Intersects, Offset};
 use std::backtrace::Backtrace;
 
 // This way of defining a circular arc on the euclidean plane is useful for offsetting at right
-// angles to the arc's tangents; we need only add or subtract from radius and everything else is
-// constant for any offset.
+// angles to the arc's tangents; we need only add or subtract from radius (depending on the
+// direction of the sweep) and everything else is constant for any offset.
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(bound = "T: Value")
+)]
 #[derive(Copy, Clone, Debug)]
 pub struct Arc<T: Value> {
     pub center: Point<T>,
     // radius must be positive.
+    #[cfg_attr(feature = "serde", serde(with = "crate::geometry::serde_finite"))]
     pub radius: Finite<T>,
     pub start_angle: Angle<T>,
     pub stop_diff: AngleDiff<T>,
 }
 
 impl<T: Value> Arc<T> {
-    // start_angle is the angle of the line tangent to the arc and intersecting with
-    // the start.
+    // angle is the direction of travel at the start, along the line tangent to the
+    // arc. This is not start_angle, which is the angle of the start from the center.
     pub fn new(
         start: Point<T>,
         stop: Point<T>,
@@ -36,10 +44,9 @@ impl<T: Value> Arc<T> {
         // Length of these two lines doesn't matter for us.
         let one = Finite::<T>::one();
         let _90deg = AngleDiff(Finite::<T>::FRAC_PI_2);
-        let start_angle = angle + _90deg;
         let start_perpendicular =
-            Line::from_point_angle(start, start_angle, one)?;
-        let midpoint_angle = (stop - start).angle();
+            Line::from_point_angle(start, angle + _90deg, one)?;
+        let midpoint_angle = (stop - start).try_angle()?;
         let midpoint_perpendicular =
             Line::from_point_angle(start.midpoint(stop), midpoint_angle + _90deg, one)?;
 
@@ -54,7 +61,20 @@ impl<T: Value> Arc<T> {
         let stop_delta = stop - center;
         let start_delta = start - center;
         let radius = start_delta.magnitude();
-        let stop_diff = stop_delta.angle() - start_delta.angle();
+        let start_angle = start_delta.try_angle()?;
+        let stop_angle = stop_delta.try_angle()?;
+
+        // The arc sweeps counterclockwise if it starts by turning left, towards the
+        // center, and may sweep any amount up to a full turn in that direction.
+        let two_pi = T::tau();
+        let ccw_sweep = Angle::from(stop_angle - start_angle).radians();
+        let heading = Delta::magnitude_angle(one, angle);
+        let turns_left = heading.cross(center - start) > Finite::<T>::zero();
+        let stop_diff = if turns_left {
+            AngleDiff(ccw_sweep)
+        } else {
+            AngleDiff(ccw_sweep - two_pi)
+        };
 
         Ok(Self {
             center,
@@ -77,8 +97,8 @@ impl<T: Value> Arc<T> {
         if abs_diff_ne!(radius.into_inner(), stop_delta.magnitude().into_inner()) {
             return curvy_err!("Undefinable circular arc");
         }
-        let start_angle = start_delta.angle();
-        let stop_diff = stop_delta.angle() - start_angle;
+        let start_angle = start_delta.try_angle()?;
+        let stop_diff = stop_delta.try_angle()? - start_angle;
         Ok(Self {
             center,
             radius,
@@ -87,33 +107,207 @@ impl<T: Value> Arc<T> {
         })
     }
 
-    pub fn apply_bounded(self, t: Finite<T>) -> Option<Point<T>> {
-        if t >= self.begin() && t <= self.end() {
-            Some(self.apply(t))
+    // Angles are counterclockwise from the positive x axis, and the arc sweeps from
+    // start_angle to stop_angle in the given direction. The arc must sweep some nonzero
+    // amount less than a full turn, so start_angle and stop_angle must differ.
+    pub fn from_parametric(
+        center: Point<T>,
+        radius: Finite<T>,
+        start_angle: Angle<T>,
+        stop_angle: Angle<T>,
+        direction: Direction,
+    ) -> CurvyResult<Self> {
+        let zero = Finite::<T>::zero();
+        if radius <= zero {
+            return curvy_err!("Arc radius must be positive");
+        }
+        let ccw_sweep = Angle::from(stop_angle - start_angle).radians();
+        if ccw_sweep == zero {
+            return curvy_err!("Arc start and stop angles are the same");
+        }
+        let two_pi = T::tau();
+        let stop_diff = match direction {
+            | Direction::Counterclockwise => AngleDiff(ccw_sweep),
+            | Direction::Clockwise => AngleDiff(ccw_sweep - two_pi),
+            | Direction::None => {
+                return curvy_err!("Arc direction must be clockwise or counterclockwise")
+            }
+        };
+        Ok(Self {
+            center,
+            radius,
+            start_angle,
+            stop_diff,
+        })
+    }
+
+    // The arc from p1 to p3 that passes through p2, on the circle through all three.
+    pub fn from_three_points(
+        p1: Point<T>,
+        p2: Point<T>,
+        p3: Point<T>,
+    ) -> CurvyResult<Self> {
+        if p1 == p2 || p2 == p3 || p1 == p3 {
+            return curvy_err!("Arc points are not distinct");
+        }
+        // Positive if p1, p2, p3 turn left, so the arc sweeps counterclockwise.
+        let turn = (p2 - p1).cross(p3 - p2);
+        if turn == Finite::<T>::zero() {
+            return curvy_err!("Arc points are collinear");
+        }
+
+        // The center is equidistant to all three points, so it lies on the
+        // perpendicular bisectors of both chords.
+        let one = Finite::<T>::one();
+        let _90deg = AngleDiff(Finite::<T>::FRAC_PI_2);
+        let first_bisector = Line::from_point_angle(
+            p1.midpoint(p2),
+            (p2 - p1).try_angle()? + _90deg,
+            one,
+        )?;
+        let second_bisector = Line::from_point_angle(
+            p2.midpoint(p3),
+            (p3 - p2).try_angle()? + _90deg,
+            one,
+        )?;
+        let center = match first_bisector.intersect(&second_bisector) {
+            | LineIntersection::OnePoint(point)
+            | LineIntersection::OutOfBounds(point) => point,
+            | _ => {
+                return curvy_err!("Undefinable circular arc");
+            }
+        };
+
+        let start_delta = p1 - center;
+        let radius = start_delta.magnitude();
+        let start_angle = start_delta.try_angle()?;
+        let stop_angle = (p3 - center).try_angle()?;
+        let direction = if turn > Finite::<T>::zero() {
+            Direction::Counterclockwise
         } else {
-            None
+            Direction::Clockwise
+        };
+        Self::from_parametric(center, radius, start_angle, stop_angle, direction)
+    }
+
+    // The arc drawn by the SVG path command "A radius,radius 0 large_arc,sweep end"
+    // from start, finding the center as in the SVG spec's endpoint to center
+    // conversion. Of the four arcs of this radius between start and end, large_arc
+    // picks those sweeping more than half a turn, and sweep picks those turning
+    // counterclockwise, as for Arc::sweep_flag. Unlike SVG renderers, this doesn't grow
+    // a radius too small to reach from start to end, but reports an error.
+    pub fn from_svg_params(
+        start: Point<T>,
+        radius: Finite<T>,
+        large_arc: bool,
+        sweep: bool,
+        end: Point<T>,
+    ) -> CurvyResult<Self> {
+        let zero = Finite::<T>::zero();
+        if start == end {
+            return curvy_err!("Start, stop points are the same");
+        }
+        if radius <= zero {
+            return curvy_err!("Arc radius must be positive");
         }
+        let midpoint = start.midpoint(end);
+        let half_chord = start - midpoint;
+        let half_chord_squared = half_chord.magnitude_squared();
+        // Squared distance from the midpoint of the chord to the center, which is
+        // slightly negative from rounding when the chord is a diameter.
+        let mut apothem_squared = radius * radius - half_chord_squared;
+        if apothem_squared < zero {
+            let epsilon = Finite::<T>::from_inner(T::EPSILON.sqrt());
+            let tolerance = half_chord_squared * epsilon;
+            if apothem_squared < -tolerance {
+                return curvy_err!("Arc radius is too small to reach from start to end");
+            }
+            apothem_squared = zero;
+        }
+        // Looking from start to end, the center is to the left for counterclockwise
+        // arcs under half a turn and clockwise arcs over half a turn, and otherwise to
+        // the right.
+        let mut scale = (apothem_squared / half_chord_squared).sqrt();
+        if large_arc == sweep {
+            scale = -scale;
+        }
+        let center = midpoint
+            + Delta {
+                dx: half_chord.dy * scale,
+                dy: -half_chord.dx * scale,
+            };
+
+        let direction = if sweep {
+            Direction::Counterclockwise
+        } else {
+            Direction::Clockwise
+        };
+        Self::from_parametric(
+            center,
+            radius,
+            (start - center).try_angle()?,
+            (end - center).try_angle()?,
+            direction,
+        )
+    }
+
+    // Parameters a whole turn apart give the same point, so t is on the arc if it is
+    // between begin() and end() after adding or taking away a turn.
+    pub fn apply_bounded(self, t: Finite<T>) -> Option<Point<T>> {
+        let two_pi = T::tau();
+        let turn = two_pi * self.radius.abs();
+        let (begin, end) = (self.begin(), self.end());
+        let (low, high) = (begin.min(end), begin.max(end));
+        [t, t + turn, t - turn]
+            .iter()
+            .any(|&t| t >= low && t <= high)
+            .then(|| self.apply(t))
     }
 
     pub fn apply_angle(self, angle: Angle<T>) -> Point<T> {
         return self.center + Delta::magnitude_angle(self.radius, angle);
     }
 
+    // The parameter t of an arc is the distance along its circle, counterclockwise from
+    // the positive x axis through the center. It is not measured from start_angle; rather,
+    // begin() and end() are the parameters of the start and stop. If the radius is
+    // negative, as when an arc is offset past its center, t runs the other way, and the
+    // point at an angle is on the opposite side of the center. As t is the same for every
+    // turn around the circle, it is only given for one turn here, from 0 to 2PI times the
+    // radius.
+    pub fn angle_at(self, t: Finite<T>) -> Angle<T> {
+        Angle::from(AngleDiff(t / self.radius))
+    }
+
+    // The inverse of angle_at, for angles from 0 to 2PI. The result is between 0 and
+    // 2PI times the radius.
+    pub fn param_at(self, angle: Angle<T>) -> Finite<T> {
+        angle.radians() * self.radius
+    }
+
     pub fn apply(self, t: Finite<T>) -> Point<T> {
-        let angle = Angle(t / self.radius);
-        return self.center + Delta::magnitude_angle(self.radius, angle);
+        self.apply_angle(self.angle_at(t))
     }
 
     pub fn signed_distance(self, point: Point<T>) -> Finite<T> {
-        (point - self.center).angle().0 * self.radius
+        let angle = Angle::from(AngleDiff((point - self.center).angle().0));
+        if self.radius < Finite::<T>::zero() {
+            self.param_at(angle + AngleDiff(Finite::<T>::PI))
+        } else {
+            self.param_at(angle)
+        }
     }
 
+    // Parameter of the start, from 0 to 2PI times the radius.
     pub fn begin(self) -> Finite<T> {
-        self.start_angle.radians() * self.radius
+        self.param_at(self.start_angle())
     }
 
+    // Parameter of the stop, following on from begin() without wrapping around at 2PI
+    // times the radius. This is less than begin() for clockwise arcs (with a positive
+    // radius).
     pub fn end(self) -> Finite<T> {
-        (self.stop_angle()).radians() * self.radius
+        self.begin() + self.length()
     }
 
     // start angle from center
@@ -130,6 +324,18 @@ impl<T: Value> Arc<T> {
         self.stop_diff.radians() * self.radius
     }
 
+    // Direction at a right angle to the arc, at the given angle from its center. As with
+    // Line::normal, this is +90deg relative to the direction of travel, so it points
+    // towards the center of a counterclockwise arc and away from the center of a
+    // clockwise arc. A positive offset moves the arc in the opposite direction.
+    pub fn normal_at_angle(self, angle: Angle<T>) -> Angle<T> {
+        if self.stop_diff.radians() < Finite::<T>::zero() {
+            angle
+        } else {
+            angle + AngleDiff(Finite::<T>::PI)
+        }
+    }
+
     pub fn start(self) -> Point<T> {
         self.apply(self.begin())
     }
@@ -138,6 +344,53 @@ impl<T: Value> Arc<T> {
         self.apply(self.end())
     }
 
+    // The end points, and the points between them where the arc is furthest along either
+    // axis: where it passes 0, 90, 180 or 270 degrees around its center. These are in
+    // order along the arc, and together they bound it.
+    pub fn axis_extrema(self) -> Vec<Point<T>> {
+        let quarter = T::frac_pi_2();
+        let mut extrema: Vec<(Finite<T>, Point<T>)> = (0..4)
+            .map(|k| Angle(quarter * Finite::<T>::from_inner(T::from_usize(k).unwrap())))
+            .map(|angle| (self.sweep_to(angle), angle))
+            .filter(|&(sweep, _)| {
+                sweep > Finite::<T>::zero() && sweep < self.stop_diff.radians().abs()
+            })
+            .map(|(sweep, angle)| {
+                (sweep, self.center + Delta::magnitude_angle(self.radius, angle))
+            })
+            .collect();
+        extrema.sort_by_key(|&(sweep, _)| sweep);
+
+        let mut points = Vec::with_capacity(extrema.len() + 2);
+        points.push(self.start());
+        points.extend(extrema.into_iter().map(|(_, point)| point));
+        points.push(self.stop());
+        points
+    }
+
+    // Direction of travel at the start of the arc. This is at a right angle to
+    // start_angle, which is the direction of the start from the center.
+    pub fn tangent_angle_at_start(self) -> Angle<T> {
+        self.tangent_angle_at(self.start_angle())
+    }
+
+    // Direction of travel at the stop of the arc. This is at a right angle to
+    // stop_angle, which is the direction of the stop from the center.
+    pub fn tangent_angle_at_stop(self) -> Angle<T> {
+        self.tangent_angle_at(self.stop_angle())
+    }
+
+    // Travel is 90deg ahead of the angle from the center for counterclockwise arcs, and
+    // 90deg behind it for clockwise arcs.
+    fn tangent_angle_at(self, angle: Angle<T>) -> Angle<T> {
+        let _90deg = AngleDiff(Finite::<T>::FRAC_PI_2);
+        if self.stop_diff.radians() < Finite::<T>::zero() {
+            angle + -_90deg
+        } else {
+            angle + _90deg
+        }
+    }
+
     pub fn control_point(self) -> Point<T> {
         // If this arc were approximated by two tangent lines at each start and end, give
         // the intersection of those two lines.
@@ -155,13 +408,7 @@ impl<T: Value> Arc<T> {
             one,
         )
         .unwrap();
-        match start_tangent.intersect(&stop_tangent) {
-            | LineIntersection::OnePoint(point)
-            | LineIntersection::OutOfBounds(point) => point,
-            | _ => {
-                panic!();
-            }
-        }
+        start_tangent.intersect(&stop_tangent).point().unwrap()
     }
 
     // If this arc were approximated by two tangent lines at each start and end, give
@@ -170,26 +417,148 @@ impl<T: Value> Arc<T> {
         self.start().distance(self.control_point())
     }
 
+    // Move the arc without turning it.
+    pub fn translate(self, delta: Delta<T>) -> Self {
+        Self {
+            center: self.center + delta,
+            ..self
+        }
+    }
+
+    // Rotate the arc counterclockwise about a point.
+    pub fn rotate_about(self, center: Point<T>, angle: Angle<T>) -> Self {
+        Self {
+            center: self.center.rotate_about(center, angle),
+            start_angle: self.start_angle + AngleDiff::from(angle),
+            ..self
+        }
+    }
+
+    // Mirror image of the arc on the other side of the axis, as for Point::reflect. A
+    // reflection turns directions around the axis, so the arc sweeps the other way.
+    pub fn reflect_across(self, axis: &Line<T>) -> Self {
+        let two = Finite::<T>::one() + Finite::<T>::one();
+        let start_angle = two * axis.angle.radians() - self.start_angle.radians();
+        Self {
+            center: self.center.reflect(axis),
+            radius: self.radius,
+            start_angle: Angle::from(AngleDiff(start_angle)),
+            stop_diff: -self.stop_diff,
+        }
+    }
+
+    // count arcs sweeping the same angles about the same center, starting with this one
+    // and each after it spacing larger in radius. Unlike offset, this grows the radius
+    // whichever way the arc turns. Arcs whose radius wouldn't be positive are left out.
+    pub fn concentric(self, spacing: Finite<T>, count: usize) -> Vec<Arc<T>> {
+        let zero = Finite::<T>::zero();
+        (0..count)
+            .map(|k| {
+                let k = Finite::<T>::from_inner(T::from_usize(k).unwrap());
+                Arc {
+                    radius: self.radius + spacing * k,
+                    ..self
+                }
+            })
+            .filter(|arc| arc.radius > zero)
+            .collect()
+    }
+
+    // Split into n arcs of equal sweep, in order from start to stop, each starting where
+    // the last stops. Returns no arcs for n = 0.
+    pub fn subdivide(self, n: usize) -> Vec<Arc<T>> {
+        if n == 0 {
+            return Vec::new();
+        }
+        let step = self.stop_diff / Finite::<T>::from_inner(T::from_usize(n).unwrap());
+        (0..n)
+            .map(|k| {
+                let k = Finite::<T>::from_inner(T::from_usize(k).unwrap());
+                Arc {
+                    start_angle: self.start_angle + step * k,
+                    stop_diff: step,
+                    ..self
+                }
+            })
+            .collect()
+    }
+
+    // How far the arc turns from its start to reach the given angle from its center,
+    // turning in the direction of the arc, from 0 to 2PI.
+    pub fn sweep_to(self, angle: Angle<T>) -> Finite<T> {
+        let ccw = AngleDiff(angle.radians() - self.start_angle.radians());
+        if self.stop_diff.radians() < Finite::<T>::zero() {
+            Angle::from(-ccw).radians()
+        } else {
+            Angle::from(ccw).radians()
+        }
+    }
+
+    // Whether the arc passes the given angle from its center.
+    pub fn contains_angle(self, angle: Angle<T>) -> bool {
+        self.sweep_to(angle) <= self.stop_diff.radians().abs()
+    }
+
+    // Greatest distance from the chord between start and stop to the arc, which is the
+    // sagitta: the distance from the middle of the chord to the middle of the arc.
+    pub fn chord_deviation(self) -> Finite<T> {
+        // cos is even, so this holds for clockwise arcs too.
+        let half_sweep = self.stop_diff.half().radians();
+        self.radius * (Finite::<T>::one() - half_sweep.cos())
+    }
+
+    // Whether the arc can be drawn as the line from start to stop without straying from
+    // the arc by more than the tolerance.
+    pub fn is_flat(self, tolerance: Finite<T>) -> bool {
+        self.chord_deviation() <= tolerance
+    }
+
+    // SVG's sweep flag, which is set for arcs turning from the x axis towards the y axis;
+    // that is, counterclockwise with the y axis pointing up. This follows the sign of
+    // stop_diff, so arcs of more than half a turn keep their own direction rather than
+    // that of the shorter way between their end points.
     pub fn sweep_flag(self) -> bool {
-        matches!(
-            self.start_angle().direction(self.stop_angle()),
-            Direction::Counterclockwise
-        )
+        self.stop_diff.radians() > Finite::<T>::zero()
+    }
+
+    // The same arc with values of another type, as for Point::cast.
+    pub fn cast<U: Value>(self) -> CurvyResult<Arc<U>> {
+        let start_angle = cast_finite(self.start_angle.radians())?;
+        Ok(Arc {
+            center: self.center.cast()?,
+            radius: cast_finite(self.radius)?,
+            start_angle: Angle::from(AngleDiff(start_angle)),
+            stop_diff: AngleDiff(cast_finite(self.stop_diff.radians())?),
+        })
+    }
+}
+
+impl Arc<f64> {
+    pub fn to_f32(self) -> CurvyResult<Arc<f32>> {
+        self.cast()
     }
 }
 
 impl<T: Value> Offset<T> for Arc<T> {
     type OffsetResult = Self;
     fn offset(self, offset: Finite<T>) -> Self::OffsetResult {
+        // -90deg from the direction of travel is away from the center for a
+        // counterclockwise arc, but towards the center for a clockwise arc.
+        let radius = if self.stop_diff.radians() < Finite::<T>::zero() {
+            self.radius - offset
+        } else {
+            self.radius + offset
+        };
         Self {
             center: self.center,
-            radius: self.radius + offset,
+            radius,
             start_angle: self.start_angle,
             stop_diff: self.stop_diff,
         }
     }
 }
 
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum ArcIntersectionPoint<T: Value> {
     InBounds(Point<T>),
     InArcBounds(Point<T>),
@@ -206,8 +575,19 @@ impl<T: Value> ArcIntersectionPoint<T> {
             | (true, true) => ArcIntersectionPoint::InBounds(point),
         }
     }
+
+    // The point, whichever bounds it lies within.
+    pub fn point(self) -> Point<T> {
+        match self {
+            | ArcIntersectionPoint::InBounds(point)
+            | ArcIntersectionPoint::InArcBounds(point)
+            | ArcIntersectionPoint::InLineBounds(point)
+            | ArcIntersectionPoint::OutOfBounds(point) => point,
+        }
+    }
 }
 
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum ArcIntersection<T: Value> {
     None,
     One(ArcIntersectionPoint<T>),
@@ -215,78 +595,48 @@ pub enum ArcIntersection<T: Value> {
     Many, // For Arc-Arc intersection only
 }
 
+impl<T: Value> ArcIntersection<T> {
+    // All the points of intersection, whichever bounds they lie within.
+    pub fn points(self) -> Vec<Point<T>> {
+        match self {
+            | ArcIntersection::One(a) => vec![a.point()],
+            | ArcIntersection::Two(a, b) => vec![a.point(), b.point()],
+            | ArcIntersection::None | ArcIntersection::Many => vec![],
+        }
+    }
+}
+
 impl<T: Value> Intersects<Line<T>> for Arc<T> {
     type Intersection = ArcIntersection<T>;
 
     fn intersect(self, line: &Line<T>) -> Self::Intersection {
-        let line_point = line.point_nearest_origin();
-        let line_distance = line.distance_from_origin;
-
-        let delta = line_point - self.center;
-
-        let a = (line_point.x * line_point.x + line_point.y * line_point.y)
-            / (line_distance * line_distance);
-        let b = (delta.dx * line_point.y - delta.dy * line_point.x) / line_distance;
-        let c = delta.dx * delta.dx + delta.dy * delta.dy - self.radius * self.radius;
-
-        let radicand = b * b - a * c;
+        // Points on the line are base + s * direction, for a unit direction. Solve for
+        // |base + s * direction - center| = radius, a quadratic in s with leading
+        // coefficient 1.
+        let (base, direction) = line.parametric_form();
+        let delta = base - self.center;
+        let b = delta.dot(direction);
+        let c = delta.magnitude_squared() - self.radius * self.radius;
+
+        let radicand = b * b - c;
         if radicand < Finite::<T>::zero() {
             return ArcIntersection::None;
         }
 
-        let line_lower_bound = line.begin();
-        let line_upper_bound = line.end();
-
-        let self_min_theta = self.start_angle();
-        let self_max_theta = self.stop_angle();
+        let intersection_point = |solution: Finite<T>| {
+            let point = line.point_along(solution);
+            let on_line_segment = solution >= line.begin() && solution <= line.end();
+            let on_circular_arc = self.contains_angle((point - self.center).angle());
+            ArcIntersectionPoint::new(on_line_segment, on_circular_arc, point)
+        };
 
         if radicand == Finite::<T>::zero() {
-            // Solutions equivalent
-            let solution = -b / a;
-            let point = line.point_along(solution);
-            let theta = (point - self.center).angle();
-            let point_on_line_segment =
-                solution >= line_lower_bound && solution < line_upper_bound;
-            let point_on_circle_segment = theta.between(self_min_theta, self_max_theta);
-            return if point_on_line_segment && point_on_circle_segment {
-                ArcIntersection::One(ArcIntersectionPoint::InBounds(point))
-            } else {
-                ArcIntersection::One(ArcIntersectionPoint::OutOfBounds(point))
-            };
+            // Solutions equivalent; the line is tangent to the circle
+            return ArcIntersection::One(intersection_point(-b));
         }
 
         let sqrt = radicand.sqrt();
-        let solution1 = (-b + sqrt) / a;
-        let solution2 = (-b - sqrt) / a;
-
-        let solution1_on_line_segment =
-            solution1 >= line_lower_bound && solution1 < line_upper_bound;
-        let solution2_on_line_segment =
-            solution2 >= line_lower_bound && solution1 < line_upper_bound;
-
-        let point1 = line.point_along(solution1);
-        let point2 = line.point_along(solution2);
-
-        let theta1 = (point1 - self.center).angle();
-        let theta2 = (point2 - self.center).angle();
-
-        let solution1_on_circle_segment =
-            theta1.between(self_min_theta, self_max_theta);
-        let solution2_on_circle_segment =
-            theta2.between(self_min_theta, self_max_theta);
-
-        ArcIntersection::Two(
-            ArcIntersectionPoint::new(
-                solution1_on_line_segment,
-                solution1_on_circle_segment,
-                point1,
-            ),
-            ArcIntersectionPoint::new(
-                solution2_on_line_segment,
-                solution2_on_circle_segment,
-                point2,
-            ),
-        )
+        ArcIntersection::Two(intersection_point(-b + sqrt), intersection_point(-b - sqrt))
     }
 }
 
@@ -297,9 +647,100 @@ impl<T: Value> Intersects<Arc<T>> for Line<T> {
     }
 }
 
+// The points where the arc crosses the edges of the rectangle, in order along the arc. A
+// circle can cross each of the four edges twice, so there are at most eight.
+impl<T: Value> Intersects<Rect<T>> for Arc<T> {
+    type Intersection = Vec<Point<T>>;
+
+    fn intersect(self, rect: &Rect<T>) -> Self::Intersection {
+        // Each edge lies on a line where one coordinate is fixed, which the circle crosses
+        // either side of the center in the other coordinate.
+        let crossings = |offset: Finite<T>, center: Finite<T>| {
+            let radicand = self.radius * self.radius - offset * offset;
+            if radicand < Finite::<T>::zero() {
+                return vec![];
+            }
+            let root = radicand.sqrt();
+            vec![center - root, center + root]
+        };
+        let mut candidates: Vec<Point<T>> = Vec::new();
+        for x in [rect.min.x, rect.max.x] {
+            for y in crossings(x - self.center.x, self.center.y) {
+                if y >= rect.min.y && y <= rect.max.y {
+                    candidates.push(Point { x, y });
+                }
+            }
+        }
+        for y in [rect.min.y, rect.max.y] {
+            for x in crossings(y - self.center.y, self.center.x) {
+                if x >= rect.min.x && x <= rect.max.x {
+                    candidates.push(Point { x, y });
+                }
+            }
+        }
+        let mut points: Vec<Point<T>> = Vec::new();
+        for point in candidates {
+            // Crossings at a corner or a tangent are found twice
+            let on_arc = self.contains_angle((point - self.center).angle());
+            if on_arc && !points.iter().any(|&p| coincident(p, point)) {
+                points.push(point);
+            }
+        }
+        points.sort_by_key(|&point| self.sweep_to((point - self.center).angle()));
+        points
+    }
+}
+
+// Points are tagged as with lines, with InArcBounds for points only within the bounds of
+// self, and InLineBounds for points only within the bounds of the other arc. Arcs of the
+// same circle give Many, whether or not they overlap.
 impl<T: Value> Intersects<Arc<T>> for Arc<T> {
     type Intersection = ArcIntersection<T>;
     fn intersect(self, arc: &Arc<T>) -> Self::Intersection {
-        todo!()
+        let zero = Finite::<T>::zero();
+        let two = Finite::<T>::one() + Finite::<T>::one();
+        let between = arc.center - self.center;
+        let distance = between.magnitude();
+        if distance == zero {
+            if self.radius == arc.radius {
+                return ArcIntersection::Many;
+            }
+            // Concentric circles of different sizes
+            return ArcIntersection::None;
+        }
+        if distance > self.radius + arc.radius
+            || distance < (self.radius - arc.radius).abs()
+        {
+            // Circles apart, or one inside the other
+            return ArcIntersection::None;
+        }
+
+        // Distance along the line between the centers to the chord through both
+        // intersections, and half the length of that chord.
+        let along = (distance * distance + self.radius * self.radius
+            - arc.radius * arc.radius)
+            / (two * distance);
+        let half_chord_squared = self.radius * self.radius - along * along;
+        let direction = between * (Finite::<T>::one() / distance);
+        let chord_middle = self.center + direction * along;
+
+        let intersection_point = |point: Point<T>| {
+            let on_self = self.contains_angle((point - self.center).angle());
+            let on_other = arc.contains_angle((point - arc.center).angle());
+            ArcIntersectionPoint::new(on_other, on_self, point)
+        };
+
+        if half_chord_squared <= zero {
+            // The circles touch at a single point
+            return ArcIntersection::One(intersection_point(chord_middle));
+        }
+        let across = Delta {
+            dx: -direction.dy,
+            dy: direction.dx,
+        } * half_chord_squared.sqrt();
+        ArcIntersection::Two(
+            intersection_point(chord_middle + across),
+            intersection_point(chord_middle + -across),
+        )
     }
 }
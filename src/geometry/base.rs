@@ -1,17 +1,87 @@
+use std::backtrace::Backtrace;
 use std::fmt::{Debug, Display};
 use std::ops::Rem;
 
 use approx::RelativeEq;
-use decorum::{Float, Primitive};
-use num_traits::cast::FromPrimitive;
+use decorum::{Finite, Float, Primitive, Real};
+use num_traits::cast::{FromPrimitive, ToPrimitive};
+use num_traits::One;
+
+use crate::geometry::error::*;
 
 pub trait Value:
-    Float + Primitive + Debug + Display + FromPrimitive + RelativeEq + Rem
+    Float
+    + Primitive
+    + Debug
+    + Display
+    + FromPrimitive
+    + ToPrimitive
+    + RelativeEq
+    + Rem
+    + MaybeSerde
 {
+    // Common constants as finite values of the type, for use with angles.
+    fn pi() -> Finite<Self> {
+        Finite::<Self>::PI
+    }
+
+    fn tau() -> Finite<Self> {
+        Finite::<Self>::PI + Finite::<Self>::PI
+    }
+
+    fn frac_pi_2() -> Finite<Self> {
+        Finite::<Self>::FRAC_PI_2
+    }
+
+    // Small constants as finite values of the type, for doubling and halving.
+    fn two() -> Finite<Self> {
+        Finite::<Self>::one() + Finite::<Self>::one()
+    }
+
+    fn half() -> Finite<Self> {
+        Finite::<Self>::one() / Self::two()
+    }
 }
 
 // Value is blanket-implemented for types like f32 and f64.
 impl<T> Value for T where
-    T: Float + Primitive + Debug + Display + FromPrimitive + RelativeEq + Rem
+    T: Float
+        + Primitive
+        + Debug
+        + Display
+        + FromPrimitive
+        + ToPrimitive
+        + RelativeEq
+        + Rem
+        + MaybeSerde
 {
 }
+
+// With the serde feature, values must be serializable, so that the geometry made of
+// them is too. Without it, this is no bound at all.
+#[cfg(feature = "serde")]
+pub trait MaybeSerde: serde::Serialize + serde::de::DeserializeOwned {}
+
+#[cfg(feature = "serde")]
+impl<T> MaybeSerde for T where T: serde::Serialize + serde::de::DeserializeOwned {}
+
+#[cfg(not(feature = "serde"))]
+pub trait MaybeSerde {}
+
+#[cfg(not(feature = "serde"))]
+impl<T> MaybeSerde for T {}
+
+// The value as another type, such as narrowing f64 to f32, which fails for values out
+// of the other type's range.
+pub(crate) fn cast_finite<T: Value, U: Value>(
+    value: Finite<T>,
+) -> CurvyResult<Finite<U>> {
+    let inner = value.into_inner();
+    match inner.to_f64().and_then(U::from_f64) {
+        | Some(cast) if cast.is_finite() => Ok(Finite::<U>::from_inner(cast)),
+        | _ => curvy_err!(
+            ErrorKind::NonFiniteValue,
+            format!("{} is out of range for the type", inner)
+        ),
+    }
+}
@@ -0,0 +1,191 @@
+use decorum::Finite;
+use num_traits::{One, Signed, Zero};
+
+use crate::geometry::arc::Arc;
+use crate::geometry::error::*;
+use crate::geometry::line::Line;
+use crate::geometry::path::PathSegment;
+use crate::geometry::poly::{Polyarc, Polycurve, Polygon, Polyline, Segmented};
+use crate::geometry::*;
+
+// Axis-aligned rectangle, as the extent of some geometry. min is the corner with the least
+// x and y, and max the corner with the greatest.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BoundingBox<T: Value> {
+    pub min: Point<T>,
+    pub max: Point<T>,
+}
+
+// Geometry which can give the smallest box containing all of its points.
+pub trait Bounded<T: Value> {
+    fn bounding_box(&self) -> BoundingBox<T>;
+}
+
+// A bounding box is also just the way to describe a rectangular area, such as a page.
+pub type Rect<T> = BoundingBox<T>;
+
+impl<T: Value> BoundingBox<T> {
+    // The smallest box containing all the points, or None if there are no points.
+    pub fn from_points<I: IntoIterator<Item = Point<T>>>(points: I) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(
+            BoundingBox {
+                min: first,
+                max: first,
+            },
+            |bounds, point| BoundingBox {
+                min: Point {
+                    x: bounds.min.x.min(point.x),
+                    y: bounds.min.y.min(point.y),
+                },
+                max: Point {
+                    x: bounds.max.x.max(point.x),
+                    y: bounds.max.y.max(point.y),
+                },
+            },
+        ))
+    }
+
+    pub fn width(&self) -> Finite<T> {
+        self.max.x - self.min.x
+    }
+
+    pub fn height(&self) -> Finite<T> {
+        self.max.y - self.min.y
+    }
+
+    pub fn center(&self) -> Point<T> {
+        self.min.midpoint(self.max)
+    }
+
+    // Whether the point is inside the box or on its edge.
+    pub fn contains_point(&self, point: Point<T>) -> bool {
+        point.x >= self.min.x
+            && point.x <= self.max.x
+            && point.y >= self.min.y
+            && point.y <= self.max.y
+    }
+
+    // The smallest box containing both boxes.
+    pub fn union(&self, other: &BoundingBox<T>) -> BoundingBox<T> {
+        BoundingBox {
+            min: Point {
+                x: self.min.x.min(other.min.x),
+                y: self.min.y.min(other.min.y),
+            },
+            max: Point {
+                x: self.max.x.max(other.max.x),
+                y: self.max.y.max(other.max.y),
+            },
+        }
+    }
+
+    // Whether the boxes overlap or touch.
+    pub fn intersects(&self, other: &BoundingBox<T>) -> bool {
+        self.min.x <= other.max.x
+            && other.min.x <= self.max.x
+            && self.min.y <= other.max.y
+            && other.min.y <= self.max.y
+    }
+
+    // The box as a polygon, counterclockwise from min. Fails for a box with no area.
+    pub fn to_polygon(&self) -> CurvyResult<Polygon<T>> {
+        Polygon::new(vec![
+            self.min,
+            Point {
+                x: self.max.x,
+                y: self.min.y,
+            },
+            self.max,
+            Point {
+                x: self.min.x,
+                y: self.max.y,
+            },
+        ])
+    }
+
+    // The parts of the arc inside the box, in order along the arc.
+    pub fn clip_arc(&self, arc: Arc<T>) -> Vec<Arc<T>> {
+        let total = arc.stop_diff.radians().abs();
+        let sign = arc.stop_diff.radians().signum();
+        let mut sweeps = vec![Finite::<T>::zero()];
+        sweeps.extend(
+            arc.intersect(self)
+                .into_iter()
+                .map(|point| arc.sweep_to((point - arc.center).angle()))
+                .filter(|&sweep| sweep > Finite::<T>::zero() && sweep < total),
+        );
+        sweeps.push(total);
+
+        let two = Finite::<T>::one() + Finite::<T>::one();
+        sweeps
+            .windows(2)
+            .map(|pair| Arc {
+                start_angle: arc.start_angle + AngleDiff(pair[0] * sign),
+                stop_diff: AngleDiff((pair[1] - pair[0]) * sign),
+                ..arc
+            })
+            .filter(|piece| {
+                let middle = piece.start_angle + AngleDiff(piece.stop_diff.radians() / two);
+                self.contains_point(piece.apply_angle(middle))
+            })
+            .collect()
+    }
+}
+
+impl<T: Value> Bounded<T> for Line<T> {
+    fn bounding_box(&self) -> BoundingBox<T> {
+        // Lines always have two points
+        BoundingBox::from_points([self.start(), self.stop()]).unwrap()
+    }
+}
+
+impl<T: Value> Bounded<T> for Arc<T> {
+    fn bounding_box(&self) -> BoundingBox<T> {
+        // Arcs always have their end points
+        BoundingBox::from_points(self.axis_extrema()).unwrap()
+    }
+}
+
+impl<T: Value> Bounded<T> for Polyline<T> {
+    fn bounding_box(&self) -> BoundingBox<T> {
+        // Polylines always have points
+        BoundingBox::from_points(self.points().iter().copied()).unwrap()
+    }
+}
+
+impl<T: Value> Bounded<T> for Polygon<T> {
+    fn bounding_box(&self) -> BoundingBox<T> {
+        // Polygons always have points
+        BoundingBox::from_points(self.points().iter().copied()).unwrap()
+    }
+}
+
+// Arcs cut inside the corners they round, so the bounds are those of the segments rather
+// than of the points.
+impl<T: Value> Bounded<T> for Polyarc<T> {
+    fn bounding_box(&self) -> BoundingBox<T> {
+        self.iter_segments()
+            .map(|segment| match segment {
+                | PathSegment::Line(line) => line.bounding_box(),
+                | PathSegment::Arc(arc) => arc.bounding_box(),
+            })
+            .reduce(|bounds, other| bounds.union(&other))
+            // Polyarcs always have segments
+            .unwrap()
+    }
+}
+
+impl<T: Value> Bounded<T> for Polycurve<T> {
+    fn bounding_box(&self) -> BoundingBox<T> {
+        self.iter_segments()
+            .map(|segment| match segment {
+                | PathSegment::Line(line) => line.bounding_box(),
+                | PathSegment::Arc(arc) => arc.bounding_box(),
+            })
+            .reduce(|bounds, other| bounds.union(&other))
+            // Polycurves always have segments
+            .unwrap()
+    }
+}
@@ -0,0 +1,61 @@
+use std::backtrace::Backtrace;
+
+use decorum::{Finite, Real};
+use num_traits::Zero;
+
+use crate::geometry::error::*;
+use crate::geometry::line::Line;
+use crate::geometry::*;
+
+// A full circle, for constructions around one rather than drawing part of it as an arc.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Circle<T: Value> {
+    pub center: Point<T>,
+    // radius must be positive.
+    pub radius: Finite<T>,
+}
+
+impl<T: Value> Circle<T> {
+    pub fn new(center: Point<T>, radius: Finite<T>) -> CurvyResult<Self> {
+        if radius <= Finite::<T>::zero() {
+            return curvy_err!("Circle radius must be positive");
+        }
+        Ok(Circle { center, radius })
+    }
+
+    // count circles about the same center, the first of start_radius and each after it
+    // spacing larger. Rings whose radius wouldn't be positive are left out.
+    pub fn concentric(
+        center: Point<T>,
+        start_radius: Finite<T>,
+        spacing: Finite<T>,
+        count: usize,
+    ) -> Vec<Circle<T>> {
+        (0..count)
+            .filter_map(|k| {
+                let k = Finite::<T>::from_inner(T::from_usize(k).unwrap());
+                Circle::new(center, start_radius + spacing * k).ok()
+            })
+            .collect()
+    }
+
+    // The two lines from point which just touch the circle, each stopping where it
+    // touches. Looking from point towards the center, the first touches on the left and
+    // the second on the right. There are none from a point inside or on the circle.
+    pub fn tangent_lines_from(&self, point: Point<T>) -> CurvyResult<(Line<T>, Line<T>)> {
+        let delta = point - self.center;
+        let distance = delta.magnitude();
+        if distance <= self.radius {
+            return curvy_err!("Point inside a circle has no tangents to it");
+        }
+        // The radius to each tangent point is at a right angle to the tangent, so with
+        // the line from the center to point, they form a right triangle.
+        let spread = AngleDiff((self.radius / distance).acos());
+        let tangent_point = |angle: Angle<T>| {
+            self.center + Delta::magnitude_angle(self.radius, angle)
+        };
+        let left = tangent_point(delta.angle() + -spread);
+        let right = tangent_point(delta.angle() + spread);
+        Ok((Line::new(point, left)?, Line::new(point, right)?))
+    }
+}
@@ -1,13 +1,19 @@
+use std::backtrace::Backtrace;
 use std::fmt;
 
 use decorum::{Finite, Real};
 use derive_more::{Add, Div, Mul, Neg, Sub};
+use num_traits::Zero;
 
+use crate::geometry::error::*;
 use crate::geometry::*;
 
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[derive(Add, Clone, Copy, Debug, Div, Eq, Mul, Neg, PartialEq, Sub)]
 pub struct Delta<T: Value> {
+    #[cfg_attr(feature = "serde", serde(with = "crate::geometry::serde_finite"))]
     pub dx: Finite<T>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::geometry::serde_finite"))]
     pub dy: Finite<T>,
 }
 
@@ -19,6 +25,17 @@ impl<T: Value> Delta<T> {
         Delta { dx, dy }
     }
 
+    // Like Delta::new(), but reports an error for dx, dy which are not finite.
+    pub fn try_new(dx: T, dy: T) -> CurvyResult<Self> {
+        if !dx.is_finite() || !dy.is_finite() {
+            return curvy_err!(
+                ErrorKind::NonFiniteValue,
+                format!("Delta ({}, {}) is not finite", dx, dy)
+            );
+        }
+        Ok(Delta::new(dx, dy))
+    }
+
     pub fn magnitude_angle(magnitude: Finite<T>, angle: Angle<T>) -> Self {
         Delta {
             dx: magnitude * angle.0.cos(),
@@ -30,8 +47,93 @@ impl<T: Value> Delta<T> {
         Angle(self.dy.atan2(self.dx))
     }
 
+    // A zero-length delta has no direction, but atan2(0, 0) is 0 rather than undefined.
+    // Unlike angle(), this reports an error for a zero delta, and normalizes the angle to
+    // between 0 and 2PI. Any other delta has an angle, however short it is.
+    pub fn try_angle(self) -> CurvyResult<Angle<T>> {
+        if self.dx.is_zero() && self.dy.is_zero() {
+            return curvy_err!(ErrorKind::ZeroVector, "Zero-length delta has no angle");
+        }
+        Ok(self.into())
+    }
+
+    // The same delta with components of another type, as for Point::map_value.
+    pub fn map_value<U: Value>(self) -> Delta<U> {
+        self.cast().unwrap()
+    }
+
+    // The same delta with components of another type, as for Point::cast.
+    pub fn cast<U: Value>(self) -> CurvyResult<Delta<U>> {
+        Ok(Delta {
+            dx: cast_finite(self.dx)?,
+            dy: cast_finite(self.dy)?,
+        })
+    }
+
+    // The z component of the cross product of the two deltas, extended into 3D. This is
+    // positive when other is counterclockwise from self, and zero when they're parallel.
+    pub fn cross(self, other: Delta<T>) -> Finite<T> {
+        self.dx * other.dy - self.dy * other.dx
+    }
+
+    // The dot product of the two deltas: the product of their magnitudes and the cosine
+    // of the angle between them, so zero when they're perpendicular.
+    pub fn dot(self, other: Delta<T>) -> Finite<T> {
+        self.dx * other.dx + self.dy * other.dy
+    }
+
     pub fn magnitude(self) -> Finite<T> {
-        return (self.dx * self.dx + self.dy * self.dy).sqrt();
+        return self.magnitude_squared().sqrt();
+    }
+
+    // Cheaper than magnitude(), for comparing magnitudes.
+    pub fn magnitude_squared(self) -> Finite<T> {
+        self.dx * self.dx + self.dy * self.dy
+    }
+
+    // The delta of length one in the same direction, or None for a zero delta, which has
+    // no direction, as for try_angle(). Deltas so short that their magnitude underflows
+    // to zero are also None.
+    pub fn normalize(self) -> Option<Delta<T>> {
+        let magnitude = self.magnitude();
+        if magnitude.is_zero() {
+            return None;
+        }
+        Some(self / magnitude)
+    }
+
+    // The delta of the given length in the same direction, or None for a zero delta, as
+    // for normalize().
+    pub fn with_magnitude(self, magnitude: Finite<T>) -> Option<Delta<T>> {
+        self.normalize().map(|unit| unit * magnitude)
+    }
+
+    // The delta of length one a quarter turn counterclockwise from this one, or a zero
+    // delta for a zero delta.
+    pub fn perpendicular(&self) -> Delta<T> {
+        match self.normalize() {
+            | Some(unit) => Delta {
+                dx: -unit.dy,
+                dy: unit.dx,
+            },
+            | None => Delta {
+                dx: Finite::<T>::zero(),
+                dy: Finite::<T>::zero(),
+            },
+        }
+    }
+
+    // The part of this delta along other: other's direction, scaled by how far this
+    // delta goes that way, which is negative where it goes the other way. Projecting
+    // onto a zero delta gives a zero delta.
+    pub fn project_onto(self, other: Delta<T>) -> Delta<T> {
+        match other.normalize() {
+            | Some(unit) => unit * self.dot(unit),
+            | None => Delta {
+                dx: Finite::<T>::zero(),
+                dy: Finite::<T>::zero(),
+            },
+        }
     }
 
     // If this Delta represents a point on a circle drawn from its center, how far
@@ -48,6 +150,26 @@ impl<T: Value> Delta<T> {
             dy: self.dx * sin + self.dy * cos,
         }
     }
+
+    // Same as rotate, for every delta in place. The sine and cosine are found once, and
+    // the loop is kept simple enough for the compiler to vectorize.
+    pub fn rotate_all(deltas: &mut [Delta<T>], angle: Angle<T>) {
+        let sin = angle.radians().sin();
+        let cos = angle.radians().cos();
+        for delta in deltas.iter_mut() {
+            let Delta { dx, dy } = *delta;
+            *delta = Delta {
+                dx: dx * cos - dy * sin,
+                dy: dx * sin + dy * cos,
+            };
+        }
+    }
+}
+
+impl Delta<f64> {
+    pub fn to_f32(self) -> CurvyResult<Delta<f32>> {
+        self.cast()
+    }
 }
 
 impl<T: Value> fmt::Display for Delta<T> {
@@ -2,16 +2,33 @@ use std::backtrace::Backtrace;
 
 use thiserror::Error;
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ErrorKind {
+    // Any error which doesn't (yet) have a more specific kind.
+    Other,
+    // A zero-length vector was given where a direction is needed.
+    ZeroVector,
+    // A coordinate or other value was infinite or NaN.
+    NonFiniteValue,
+    // Geometry was offset inwards past where it shrinks away to nothing.
+    Collapsed,
+}
+
 #[derive(Debug, Error)]
 #[error("{message}\n{backtrace}")]
 pub struct CurvyError {
+    pub kind: ErrorKind,
     pub message: String,
     pub backtrace: Backtrace,
 }
 
 macro_rules! curvy_err {
     ($msg:expr) => {
+        curvy_err!($crate::geometry::error::ErrorKind::Other, $msg)
+    };
+    ($kind:expr, $msg:expr) => {
         Err(CurvyError {
+            kind: $kind,
             message: ($msg).to_string(),
             backtrace: Backtrace::capture(),
         })
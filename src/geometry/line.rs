@@ -1,4 +1,6 @@
 use decorum::{Finite, Real};
+use num_traits::identities::{One, Zero};
+use num_traits::ToPrimitive;
 
 use crate::geometry::error::*;
 use crate::geometry::*;
@@ -7,44 +9,57 @@ use std::backtrace::Backtrace;
 
 // This way of defining a line segment on the euclidean plane is useful for offsetting at right
 // angles to the direction of the line; we need only add or subtract from distance_from_origin.
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(bound = "T: Value")
+)]
 #[derive(Copy, Clone, Debug)]
 pub struct Line<T: Value> {
     pub angle: Angle<T>,
     // distance_from_origin, can be negative for lines of different orientation
+    #[cfg_attr(feature = "serde", serde(with = "crate::geometry::serde_finite"))]
     pub distance_from_origin: Finite<T>,
     // stop and start are the distance from the point on the line closest to the origin.
     // If stop < start, then the line is considered to have negative length, and no
     // points exist on the line; this is usually not desired.
+    #[cfg_attr(feature = "serde", serde(with = "crate::geometry::serde_finite"))]
     begin: Finite<T>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::geometry::serde_finite"))]
     end: Finite<T>,
 }
 
 impl<T: Value> Line<T> {
+    // The line takes its angle from the direction from start to stop, so start() and stop()
+    // give back start and stop, in that order.
     pub fn new(start: Point<T>, stop: Point<T>) -> CurvyResult<Self> {
+        Self::new_directed(start, stop)
+    }
+
+    // Same as Line::new, for callers which depend on start() being the given start point,
+    // such as when following the points of a polyline in order.
+    pub fn new_directed(start: Point<T>, stop: Point<T>) -> CurvyResult<Self> {
         if start == stop {
             return curvy_err!("Start, stop points are the same");
         }
 
         let line_delta = stop - start;
-        let line_angle: Angle<T> = line_delta.into();
+        let line_angle = line_delta.try_angle()?;
 
         let d1 = (start - Point::origin()).rotate(-line_angle);
         let d2 = (stop - Point::origin()).rotate(-line_angle);
 
+        // Measured along the direction from start to stop, stop is further along, unless
+        // the points are too close together for the difference to survive rounding.
         let begin = d1.dx;
         let end = d2.dx;
-
-        assert_gt!(end, begin);
-
-        let (distance_from_origin, begin, end) = if begin < end {
-            (d1.dy, begin, end)
-        } else {
-            (-d1.dy, end, begin)
-        };
+        if end <= begin {
+            return curvy_err!("Start, stop points are too close to define a line");
+        }
 
         Ok(Self {
             angle: line_angle,
-            distance_from_origin,
+            distance_from_origin: d1.dy,
             begin,
             end,
         })
@@ -69,6 +84,12 @@ impl<T: Value> Line<T> {
         }
     }
 
+    // Direction at a right angle to the line, at +90deg relative to its direction. A
+    // positive offset moves the line in the opposite direction.
+    pub fn normal(self) -> Angle<T> {
+        self.angle + AngleDiff(Finite::<T>::FRAC_PI_2)
+    }
+
     pub fn point_along(self, signed_distance: Finite<T>) -> Point<T> {
         self.point_nearest_origin()
             + Delta::magnitude_angle(signed_distance, self.angle)
@@ -82,6 +103,15 @@ impl<T: Value> Line<T> {
             )
     }
 
+    // The line as base_point + t * direction, where direction is a unit vector, for t
+    // from begin() to end().
+    pub fn parametric_form(self) -> (Point<T>, Delta<T>) {
+        (
+            self.point_nearest_origin(),
+            Delta::magnitude_angle(Finite::<T>::one(), self.angle),
+        )
+    }
+
     pub fn apply_bounded(self, t: Finite<T>) -> Option<Point<T>> {
         if t >= self.begin && t <= self.end {
             Some(self.apply(t))
@@ -100,6 +130,17 @@ impl<T: Value> Line<T> {
         return delta.rotate(-self.angle).dx;
     }
 
+    // The point on this line segment which is closest to the given point.
+    pub fn nearest_point(self, point: Point<T>) -> Point<T> {
+        let t = self.signed_distance(point);
+        self.apply(t.max(self.begin).min(self.end))
+    }
+
+    // How far the point is from the nearest point on this line segment.
+    pub fn distance_to_point(self, point: Point<T>) -> Finite<T> {
+        self.nearest_point(point).distance(point)
+    }
+
     pub fn length(self) -> Finite<T> {
         self.end - self.begin
     }
@@ -120,6 +161,125 @@ impl<T: Value> Line<T> {
         self.apply(self.end)
     }
 
+    // Move the line at a right angle to its direction. As with Offset, a positive offset
+    // moves the line towards -90deg relative to its direction, which is to the right of
+    // the direction of travel with the y axis pointing up (or to the left with the y axis
+    // pointing down, as in SVG). The point nearest the origin is at +90deg, so the
+    // distance from the origin shrinks.
+    pub fn signed_offset(self, signed_offset: Finite<T>) -> Line<T> {
+        Self {
+            angle: self.angle,
+            distance_from_origin: self.distance_from_origin - signed_offset,
+            begin: self.begin,
+            end: self.end,
+        }
+    }
+
+    // The indices of the square grid cells, of the given size, which the line segment
+    // passes through, in order from start() to stop(). Cell (i, j) covers x from
+    // i * cell_size to (i + 1) * cell_size, and likewise for y. Where the line passes
+    // exactly through a grid corner, it steps diagonally to the next cell. The cell size
+    // must be positive, and large enough that the indices fit in an i64.
+    pub fn rasterize(self, cell_size: Finite<T>) -> CurvyResult<Vec<(i64, i64)>> {
+        let zero = Finite::<T>::zero();
+        let one = Finite::<T>::one();
+        if cell_size <= zero {
+            return curvy_err!("Cell size must be positive");
+        }
+        let start = self.start();
+        let stop = self.stop();
+        let (x0, y0) = (start.x / cell_size, start.y / cell_size);
+        let (x1, y1) = (stop.x / cell_size, stop.y / cell_size);
+        let cells = [x0, y0, x1, y1].map(|value| value.floor().to_i64());
+        let [mut i, mut j, i_stop, j_stop] = match cells {
+            | [Some(i), Some(j), Some(i_stop), Some(j_stop)] => [i, j, i_stop, j_stop],
+            | _ => return curvy_err!("Cell size is too small for the line"),
+        };
+        // The walk reaches the stop cell in at most this many steps, even if rounding
+        // has it take a different route.
+        let max_steps = i_stop
+            .checked_sub(i)
+            .zip(j_stop.checked_sub(j))
+            .and_then(|(di, dj)| di.checked_abs()?.checked_add(dj.checked_abs()?));
+        let max_steps = match max_steps {
+            | Some(max_steps) => max_steps,
+            | None => return curvy_err!("Cell size is too small for the line"),
+        };
+
+        // Walk the grid as a DDA: t runs from 0 at the start to 1 at the stop, and
+        // t_max is the value of t at which the line crosses into the next column or row.
+        let axis = |from: Finite<T>, to: Finite<T>, index: i64| {
+            let delta = to - from;
+            if delta > zero {
+                let boundary = Finite::<T>::from_inner(T::from_i64(index + 1).unwrap());
+                (1, (boundary - from) / delta, one / delta)
+            } else if delta < zero {
+                let boundary = Finite::<T>::from_inner(T::from_i64(index).unwrap());
+                (-1, (boundary - from) / delta, -one / delta)
+            } else {
+                // Never crosses a boundary along this axis.
+                (0, Finite::<T>::from_inner(T::MAX), zero)
+            }
+        };
+        let (step_i, mut t_max_i, t_delta_i) = axis(x0, x1, i);
+        let (step_j, mut t_max_j, t_delta_j) = axis(y0, y1, j);
+
+        // Crossings this close together are taken to be through a grid corner.
+        let tolerance = Finite::<T>::from_inner(T::EPSILON.sqrt());
+        let mut cells = vec![(i, j)];
+        for _ in 0..max_steps {
+            if (i, j) == (i_stop, j_stop) {
+                break;
+            }
+            if t_max_i < t_max_j - tolerance {
+                i += step_i;
+                t_max_i += t_delta_i;
+            } else if t_max_j < t_max_i - tolerance {
+                j += step_j;
+                t_max_j += t_delta_j;
+            } else {
+                i += step_i;
+                j += step_j;
+                t_max_i += t_delta_i;
+                t_max_j += t_delta_j;
+            }
+            cells.push((i, j));
+        }
+        Ok(cells)
+    }
+
+    // Move the line without turning it. The part of the delta along the line moves the
+    // ends, and the part at a right angle to it moves the line relative to the origin.
+    pub fn translate(self, delta: Delta<T>) -> Self {
+        let along = delta.rotate(-self.angle);
+        Self {
+            angle: self.angle,
+            distance_from_origin: self.distance_from_origin + along.dy,
+            begin: self.begin + along.dx,
+            end: self.end + along.dx,
+        }
+    }
+
+    // Rotate the line counterclockwise about a point. Rotating about the origin only
+    // changes the angle, so move the center to the origin and back.
+    pub fn rotate_about(self, center: Point<T>, angle: Angle<T>) -> Self {
+        let offset = center - Point::origin();
+        let about_origin = self.translate(-offset);
+        Self {
+            angle: about_origin.angle + AngleDiff::from(angle),
+            ..about_origin
+        }
+        .translate(offset)
+    }
+
+    // Mirror image of the line on the other side of the axis, as for Point::reflect,
+    // still running from the image of start to the image of stop.
+    pub fn reflect_across(self, axis: &Line<T>) -> Self {
+        // Reflection keeps points the same distance apart, so the ends stay apart
+        let (start, stop) = (self.start().reflect(axis), self.stop().reflect(axis));
+        Line::new_directed(start, stop).unwrap()
+    }
+
     pub fn herefrom(self, point: Point<T>) -> Self {
         Line {
             angle: self.angle,
@@ -137,17 +297,28 @@ impl<T: Value> Line<T> {
             end: self.signed_distance(point),
         }
     }
+
+    // The same line with values of another type, as for Point::cast.
+    pub fn cast<U: Value>(self) -> CurvyResult<Line<U>> {
+        Ok(Line {
+            angle: Angle::from(AngleDiff(cast_finite(self.angle.radians())?)),
+            distance_from_origin: cast_finite(self.distance_from_origin)?,
+            begin: cast_finite(self.begin)?,
+            end: cast_finite(self.end)?,
+        })
+    }
+}
+
+impl Line<f64> {
+    pub fn to_f32(self) -> CurvyResult<Line<f32>> {
+        self.cast()
+    }
 }
 
 impl<T: Value> Offset<T> for Line<T> {
     type OffsetResult = Self;
     fn offset(self, offset: Finite<T>) -> Self::OffsetResult {
-        Self {
-            angle: self.angle,
-            distance_from_origin: self.distance_from_origin + offset,
-            begin: self.begin,
-            end: self.end,
-        }
+        self.signed_offset(offset)
     }
 }
 
@@ -160,6 +331,18 @@ pub enum LineIntersection<T: Value> {
     ManyOutOfBounds,
 }
 
+impl<T: Value> LineIntersection<T> {
+    // The single point where the lines cross, whether or not it is within both segments.
+    pub fn point(self) -> Option<Point<T>> {
+        match self {
+            | LineIntersection::OnePoint(point) | LineIntersection::OutOfBounds(point) => {
+                Some(point)
+            }
+            | _ => None,
+        }
+    }
+}
+
 impl<T: Value> Intersects<Line<T>> for Line<T> {
     type Intersection = LineIntersection<T>;
 
@@ -184,18 +367,14 @@ impl<T: Value> Intersects<Line<T>> for Line<T> {
             }
         }
         // Now we know there is at most one unique possible intersection.
-        let self_point = self.point_nearest_origin();
-        let other_point = other.point_nearest_origin();
-
-        let origin = Point::origin();
-
-        let self_delta = self_point - origin;
-        let other_delta = other_point - origin;
-
-        let A = self_delta.magnitude();
-        let a = self_delta.angle().radians();
-        let B = other_delta.magnitude();
-        let b = other_delta.angle().radians();
+        // Each line is the set of points whose projection onto its normal is its
+        // distance_from_origin. Take the normal from the line's angle rather than from
+        // the point nearest the origin, which has no direction for lines through the
+        // origin.
+        let A = self.distance_from_origin;
+        let a = self.normal().radians();
+        let B = other.distance_from_origin;
+        let b = other.normal().radians();
         let sin_a = a.sin();
         let sin_b = b.sin();
         let cos_a = a.cos();
@@ -0,0 +1,72 @@
+use decorum::Finite;
+use num_traits::identities::Zero;
+
+use crate::geometry::bounds::{Bounded, BoundingBox};
+use crate::geometry::line::{Line, LineIntersection};
+use crate::geometry::*;
+use crate::geometry::Intersects;
+
+// A fixed set of line segments, indexed for intersecting many query lines against all of
+// them. Segments are sorted by their least x, so only those whose x range could overlap
+// the query's are tested. This prunes well unless some segments are much wider than most.
+#[derive(Clone, Debug)]
+pub struct LineSet<T: Value> {
+    // Sorted by bounds.min.x, with each line's index in the original order.
+    entries: Vec<(BoundingBox<T>, usize, Line<T>)>,
+    // Width of the widest segment's bounds.
+    max_width: Finite<T>,
+}
+
+impl<T: Value> LineSet<T> {
+    pub fn new(lines: Vec<Line<T>>) -> Self {
+        let mut entries: Vec<_> = lines
+            .into_iter()
+            .enumerate()
+            .map(|(index, line)| (line.bounding_box(), index, line))
+            .collect();
+        entries.sort_by_key(|(bounds, _, _)| bounds.min.x);
+        let max_width = entries
+            .iter()
+            .map(|(bounds, _, _)| bounds.width())
+            .max()
+            .unwrap_or_else(Finite::<T>::zero);
+        LineSet { entries, max_width }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    // Points where the query crosses segments of the set, in the order the segments were
+    // given. As with Line::intersect, segments along the query give no points.
+    pub fn intersect_line(&self, query: &Line<T>) -> Vec<Point<T>> {
+        let query_bounds = query.bounding_box();
+        // Segments starting further left than this can't reach the query.
+        let least_x = query_bounds.min.x - self.max_width;
+        let first = self
+            .entries
+            .partition_point(|(bounds, _, _)| bounds.min.x < least_x);
+        let last = self
+            .entries
+            .partition_point(|(bounds, _, _)| bounds.min.x <= query_bounds.max.x);
+
+        let mut hits: Vec<(usize, Point<T>)> = self.entries[first..last]
+            .iter()
+            .filter(|(bounds, _, _)| {
+                bounds.max.x >= query_bounds.min.x
+                    && bounds.min.y <= query_bounds.max.y
+                    && bounds.max.y >= query_bounds.min.y
+            })
+            .filter_map(|(_, index, line)| match query.intersect(line) {
+                | LineIntersection::OnePoint(point) => Some((*index, point)),
+                | _ => None,
+            })
+            .collect();
+        hits.sort_by_key(|(index, _)| *index);
+        hits.into_iter().map(|(_, point)| point).collect()
+    }
+}
@@ -4,12 +4,20 @@ pub mod error;
 pub mod angle;
 pub mod arc;
 pub mod base;
+pub mod bounds;
+pub mod circle;
 pub mod delta;
 pub mod intersects;
 pub mod line;
+pub mod line_set;
 pub mod offset;
+pub mod path;
 pub mod point;
 pub mod poly;
+pub mod prepared;
+#[cfg(feature = "serde")]
+mod serde_finite;
+pub mod transform;
 
 pub use angle::*;
 pub use base::*;
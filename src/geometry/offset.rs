@@ -4,7 +4,8 @@ use crate::geometry::base::*;
 
 // Positive offset means in the direction of -90deg, negative offset is in the direction of 90deg,
 // relative to the direction of the line or curve. This means that counterclockwise polygons are
-// outset when offset is positive, and inset when offset is negative.
+// outset when offset is positive, and inset when offset is negative. Angles are measured with
+// the y axis pointing up; with the y axis pointing down (as in SVG) -90deg is to the left.
 pub trait Offset<T: Value> {
     type OffsetResult;
     fn offset(self, offset: Finite<T>) -> Self::OffsetResult;
@@ -0,0 +1,180 @@
+use std::backtrace::Backtrace;
+
+use decorum::Finite;
+use num_traits::{One, Signed, Zero};
+
+use crate::geometry::arc::Arc;
+use crate::geometry::bounds::Rect;
+use crate::geometry::error::*;
+use crate::geometry::line::Line;
+use crate::geometry::*;
+
+// Any one of the segments which can make up a path.
+#[derive(Copy, Clone, Debug)]
+pub enum PathSegment<T: Value> {
+    Line(Line<T>),
+    Arc(Arc<T>),
+}
+
+impl<T: Value> PathSegment<T> {
+    pub fn start(self) -> Point<T> {
+        match self {
+            | PathSegment::Line(line) => line.start(),
+            | PathSegment::Arc(arc) => arc.start(),
+        }
+    }
+
+    pub fn stop(self) -> Point<T> {
+        match self {
+            | PathSegment::Line(line) => line.stop(),
+            | PathSegment::Arc(arc) => arc.stop(),
+        }
+    }
+
+    // Distance along the segment from start to stop, whichever way an arc turns.
+    pub fn length(self) -> Finite<T> {
+        match self {
+            | PathSegment::Line(line) => line.length(),
+            | PathSegment::Arc(arc) => arc.length().abs(),
+        }
+    }
+
+    // The point a fraction of the way along the segment by distance, from 0 at the start
+    // to 1 at the stop.
+    pub fn point_at_fraction(self, fraction: Finite<T>) -> Point<T> {
+        match self {
+            | PathSegment::Line(line) => line.start().lerp(line.stop(), fraction),
+            | PathSegment::Arc(arc) => {
+                arc.apply_angle(arc.start_angle + arc.stop_diff * fraction)
+            }
+        }
+    }
+}
+
+// A sequence of line and arc segments, each of which starts where the previous one stops.
+#[derive(Clone, Debug)]
+pub struct Path<T: Value>(Vec<PathSegment<T>>);
+
+impl<'a, T: Value> Path<T> {
+    pub fn new(segments: Vec<PathSegment<T>>) -> CurvyResult<Self> {
+        if segments.is_empty() {
+            return curvy_err!("Path requires at least one segment");
+        }
+        for pair in segments.windows(2) {
+            if !coincident(pair[0].stop(), pair[1].start()) {
+                return curvy_err!("Path segment doesn't start where the previous one stops");
+            }
+        }
+        Ok(Path(segments))
+    }
+
+    pub fn segments(&'a self) -> &'a Vec<PathSegment<T>> {
+        &self.0
+    }
+
+    pub fn start(&self) -> Point<T> {
+        self.0[0].start()
+    }
+
+    pub fn stop(&self) -> Point<T> {
+        self.0[self.0.len() - 1].stop()
+    }
+
+    // Total distance along every segment.
+    pub fn length(&self) -> Finite<T> {
+        self.0
+            .iter()
+            .fold(Finite::<T>::zero(), |length, segment| length + segment.length())
+    }
+
+    // n points spread evenly along the path by distance, from its start to its stop. A
+    // single point is the start.
+    pub fn sample_uniform(&self, n: usize) -> Vec<Point<T>> {
+        if n < 2 {
+            return self.0.iter().take(n).map(|segment| segment.start()).collect();
+        }
+        let spacing =
+            self.length() / Finite::<T>::from_inner(T::from_usize(n - 1).unwrap());
+        let mut points = Vec::with_capacity(n);
+        let mut index = 0;
+        // Distance along the path to the start of the segment at index
+        let mut segment_start = Finite::<T>::zero();
+        for sample in 0..(n - 1) {
+            let sample = Finite::<T>::from_inner(T::from_usize(sample).unwrap());
+            let distance = spacing * sample;
+            while index + 1 < self.0.len()
+                && segment_start + self.0[index].length() <= distance
+            {
+                segment_start += self.0[index].length();
+                index += 1;
+            }
+            let segment = self.0[index];
+            let fraction = (distance - segment_start) / segment.length();
+            points.push(segment.point_at_fraction(fraction.min(Finite::<T>::one())));
+        }
+        points.push(self.stop());
+        points
+    }
+
+    // Whether the path stops where it starts.
+    pub fn is_closed(&self) -> bool {
+        coincident(self.start(), self.stop())
+    }
+
+    // Closed path around the rectangle with each corner rounded by a quarter circle,
+    // counterclockwise from the bottom edge. The radius is clamped to half the smaller
+    // side; edges left with no length by the clamping are left out.
+    pub fn rounded_rect(rect: Rect<T>, radius: Finite<T>) -> CurvyResult<Self> {
+        let zero = Finite::<T>::zero();
+        let two = Finite::<T>::one() + Finite::<T>::one();
+        if rect.width() <= zero || rect.height() <= zero {
+            return curvy_err!("Rounded rectangle requires a positive width and height");
+        }
+        if radius <= zero {
+            return curvy_err!("Rounded rectangle requires a positive radius");
+        }
+        let radius = radius.min(rect.width() / two).min(rect.height() / two);
+        let (left, right) = (rect.min.x + radius, rect.max.x - radius);
+        let (bottom, top) = (rect.min.y + radius, rect.max.y - radius);
+        // Centers of the corners, in order around the rectangle
+        let centers = [
+            Point { x: right, y: bottom },
+            Point { x: right, y: top },
+            Point { x: left, y: top },
+            Point { x: left, y: bottom },
+        ];
+
+        let quarter = T::frac_pi_2();
+        let mut segments = Vec::with_capacity(8);
+        let mut angle = Angle(quarter * two + quarter);
+        for (index, &center) in centers.iter().enumerate() {
+            // The edge leading into the corner, from the end of the previous corner
+            let previous = centers[(index + centers.len() - 1) % centers.len()];
+            let start = previous + Delta::magnitude_angle(radius, angle);
+            let stop = center + Delta::magnitude_angle(radius, angle);
+            if let Ok(line) = Line::new_directed(start, stop) {
+                segments.push(PathSegment::Line(line));
+            }
+            segments.push(PathSegment::Arc(Arc {
+                center,
+                radius,
+                start_angle: angle,
+                stop_diff: AngleDiff(quarter),
+            }));
+            angle = angle + AngleDiff(quarter);
+        }
+        Path::new(segments)
+    }
+}
+
+// Whether two points are the same, allowing for error accumulated by calculating them
+// through different segments.
+pub(crate) fn coincident<T: Value>(a: Point<T>, b: Point<T>) -> bool {
+    let tolerance = T::EPSILON.sqrt();
+    let scale = Finite::<T>::one()
+        .max(a.x.abs())
+        .max(a.y.abs())
+        .max(b.x.abs())
+        .max(b.y.abs());
+    a.distance(b).into_inner() <= tolerance * scale.into_inner()
+}
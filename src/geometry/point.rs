@@ -1,16 +1,26 @@
+use std::backtrace::Backtrace;
+use std::cmp::Ordering;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::ops::{Add, Sub};
 
-use approx::AbsDiffEq;
-use decorum::Finite;
-use num_traits::identities::Zero;
+use approx::{AbsDiffEq, RelativeEq};
+use decorum::{Finite, Real};
+use num_traits::identities::{One, Zero};
+use num_traits::Signed;
 
+use crate::geometry::error::*;
+use crate::geometry::line::Line;
+use crate::geometry::transform::Affine;
 use crate::geometry::*;
 
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Point<T: Value> {
+    #[cfg_attr(feature = "serde", serde(with = "crate::geometry::serde_finite"))]
     pub x: Finite<T>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::geometry::serde_finite"))]
     pub y: Finite<T>,
 }
 
@@ -22,6 +32,32 @@ impl<T: Value> Point<T> {
         Point { x, y }
     }
 
+    // Like Point::new(), but reports an error for x, y which are not finite.
+    pub fn try_new(x: T, y: T) -> CurvyResult<Self> {
+        if !x.is_finite() || !y.is_finite() {
+            return curvy_err!(
+                ErrorKind::NonFiniteValue,
+                format!("Point ({}, {}) is not finite", x, y)
+            );
+        }
+        Ok(Point::new(x, y))
+    }
+
+    // The same point with coordinates of another type, such as f32 for storage. Panics
+    // if a coordinate is out of the other type's range; see cast.
+    pub fn map_value<U: Value>(self) -> Point<U> {
+        self.cast().unwrap()
+    }
+
+    // The same point with coordinates of another type, failing if either is out of the
+    // other type's range, unlike map_value.
+    pub fn cast<U: Value>(self) -> CurvyResult<Point<U>> {
+        Ok(Point {
+            x: cast_finite(self.x)?,
+            y: cast_finite(self.y)?,
+        })
+    }
+
     pub fn origin() -> Self {
         Point {
             x: Finite::<T>::zero(),
@@ -29,23 +65,169 @@ impl<T: Value> Point<T> {
         }
     }
 
+    // The point at the given distance from the origin, in the given direction.
+    pub fn polar(radius: Finite<T>, angle: Angle<T>) -> Self {
+        Self::polar_about(Self::origin(), radius, angle)
+    }
+
+    // The point at the given distance from center, in the given direction.
+    pub fn polar_about(center: Self, radius: Finite<T>, angle: Angle<T>) -> Self {
+        center + Delta::magnitude_angle(radius, angle)
+    }
+
     pub fn midpoint(self: Self, other: Self) -> Self {
-        let two = Finite::<T>::from_inner(T::from_f64(2.0).unwrap());
+        let two = T::two();
         Point {
             x: (self.x + other.x) / two,
             y: (self.y + other.y) / two,
         }
     }
 
+    // The point a fraction t of the way from self to other. t may be outside 0 to 1, to
+    // extrapolate beyond either point.
+    pub fn lerp(self, other: Point<T>, t: Finite<T>) -> Point<T> {
+        let one = Finite::<T>::one();
+        Point {
+            x: self.x * (one - t) + other.x * t,
+            y: self.y * (one - t) + other.y * t,
+        }
+    }
+
+    // Mean position of the points, or None if there are none.
+    pub fn centroid<I: IntoIterator<Item = Point<T>>>(points: I) -> Option<Point<T>> {
+        let mut count = 0;
+        let mut sum_x = Finite::<T>::zero();
+        let mut sum_y = Finite::<T>::zero();
+        for point in points {
+            count += 1;
+            sum_x += point.x;
+            sum_y += point.y;
+        }
+        if count == 0 {
+            return None;
+        }
+        let count = Finite::<T>::from_inner(T::from_usize(count).unwrap());
+        Some(Point {
+            x: sum_x / count,
+            y: sum_y / count,
+        })
+    }
+
     pub fn distance(self: Self, other: Point<T>) -> Finite<T> {
         (self - other).magnitude()
     }
 
+    // Cheaper than distance(), for comparing distances, such as to find the nearest of
+    // many points.
+    pub fn distance_squared(self, other: Point<T>) -> Finite<T> {
+        (self - other).magnitude_squared()
+    }
+
+    // The closest of the candidates, or None if there are none. Of candidates equally
+    // close, the first.
+    pub fn nearest<'a, I: IntoIterator<Item = &'a Point<T>>>(
+        self,
+        candidates: I,
+    ) -> Option<&'a Point<T>> {
+        candidates
+            .into_iter()
+            .min_by_key(|candidate| self.distance_squared(**candidate))
+    }
+
+    // Distance along the axes, as on a grid, rather than directly.
+    pub fn manhattan_distance(self, other: Point<T>) -> Finite<T> {
+        let delta = self - other;
+        delta.dx.abs() + delta.dy.abs()
+    }
+
+    // Mirror image of the point on the other side of the line, which is taken to go on
+    // forever either way. Points on the line stay where they are.
+    pub fn reflect(self, line: &Line<T>) -> Point<T> {
+        let two = Finite::<T>::one() + Finite::<T>::one();
+        let (base, direction) = line.parametric_form();
+        let along = self - base;
+        let t = along.dot(direction);
+        let foot = base + direction * t;
+        self + (foot - self) * two
+    }
+
+    // The nearest point within the box from min to max, moving along each axis only as
+    // far as needed. Panics if min is above or to the right of max. This takes the place
+    // of Ord::clamp, which would compare whole points lexicographically.
+    pub fn clamp(self, min: Point<T>, max: Point<T>) -> Point<T> {
+        assert!(
+            min.x <= max.x && min.y <= max.y,
+            "Clamping to an empty box from {} to {}",
+            min,
+            max
+        );
+        Point {
+            x: self.x.max(min.x).min(max.x),
+            y: self.y.max(min.y).min(max.y),
+        }
+    }
+
     pub fn rotate_about(self: Self, other: Point<T>, angle: Angle<T>) -> Point<T> {
         let delta = self - other;
         let new_delta = delta.rotate(angle);
         other + new_delta
     }
+
+    // Scale the point away from (or towards) the pivot by factor.dx along the x axis and
+    // factor.dy along the y axis. A factor of zero collapses onto the pivot, and a negative
+    // factor reflects across it.
+    pub fn scale_about(self, pivot: Point<T>, factor: Delta<T>) -> Point<T> {
+        let delta = self - pivot;
+        pivot
+            + Delta {
+                dx: delta.dx * factor.dx,
+                dy: delta.dy * factor.dy,
+            }
+    }
+
+    // Same as Affine::apply, for every point in place, in a loop kept simple enough for
+    // the compiler to vectorize.
+    pub fn transform_all(points: &mut [Point<T>], transformation: &Affine<T>) {
+        let Affine {
+            xx,
+            xy,
+            yx,
+            yy,
+            tx,
+            ty,
+        } = *transformation;
+        for point in points.iter_mut() {
+            let Point { x, y } = *point;
+            *point = Point {
+                x: xx * x + xy * y + tx,
+                y: yx * x + yy * y + ty,
+            };
+        }
+    }
+}
+
+// Spread n points evenly over a disk, as seeds are arranged in a sunflower head. Each
+// point turns by the golden angle from the last, and is placed so that every point has
+// about the same area of the disk to itself (Vogel's model). No point lies on the edge.
+pub fn sunflower<T: Value>(center: Point<T>, radius: Finite<T>, n: usize) -> Vec<Point<T>> {
+    let golden_angle = f64::PI * (3.0 - 5.0_f64.sqrt());
+    let half = T::half();
+    let count = Finite::<T>::from_inner(T::from_usize(n).unwrap());
+    (0..n)
+        .map(|k| {
+            let index = Finite::<T>::from_inner(T::from_usize(k).unwrap());
+            let distance = radius * ((index + half) / count).sqrt();
+            let turn = Finite::<T>::from_inner(T::from_f64(golden_angle).unwrap()) * index;
+            center + Delta::magnitude_angle(distance, Angle::from(AngleDiff(turn)))
+        })
+        .collect()
+}
+
+impl Point<f64> {
+    // Narrowed for storage or rendering, such as on a GPU.
+    pub fn to_f32(self) -> CurvyResult<Point<f32>> {
+        self.cast()
+    }
 }
 
 impl<T: Value> fmt::Display for Point<T> {
@@ -54,6 +236,57 @@ impl<T: Value> fmt::Display for Point<T> {
     }
 }
 
+// Like Point::new(), these will panic if x, y are not finite.
+impl<T: Value> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl<T: Value> From<[T; 2]> for Point<T> {
+    fn from([x, y]: [T; 2]) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl<T: Value> From<Point<T>> for (T, T) {
+    fn from(point: Point<T>) -> Self {
+        (point.x.into_inner(), point.y.into_inner())
+    }
+}
+
+impl<T: Value> From<Point<T>> for [T; 2] {
+    fn from(point: Point<T>) -> Self {
+        [point.x.into_inner(), point.y.into_inner()]
+    }
+}
+
+// Coordinates are finite, so equality is total, whatever T is. Deriving Eq and Hash would
+// need them for T itself.
+impl<T: Value> Eq for Point<T> {}
+
+// Lexicographic, by x and then by y, as for sweeping across points from left to right.
+impl<T: Value> Ord for Point<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.x.cmp(&other.x).then_with(|| self.y.cmp(&other.y))
+    }
+}
+
+impl<T: Value> PartialOrd for Point<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Finite values hash their canonical bits, so equal points hash the same even where a
+// coordinate is zero in one and negative zero in the other.
+impl<T: Value> Hash for Point<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.x.hash(state);
+        self.y.hash(state);
+    }
+}
+
 impl<T: Value> AbsDiffEq<Point<T>> for Point<T> where T::Epsilon: Copy {
     type Epsilon = T::Epsilon;
     fn default_epsilon() -> Self::Epsilon {
@@ -70,6 +303,25 @@ impl<T: Value> AbsDiffEq<Point<T>> for Point<T> where T::Epsilon: Copy {
     }
 }
 
+// Compares each coordinate relative to its size, for points far from the origin.
+impl<T: Value> RelativeEq<Point<T>> for Point<T> where T::Epsilon: Copy {
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Point<T>,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        let (x, y) = (self.x.into_inner(), self.y.into_inner());
+        let (other_x, other_y) = (other.x.into_inner(), other.y.into_inner());
+        x.relative_eq(&other_x, epsilon, max_relative)
+            && y.relative_eq(&other_y, epsilon, max_relative)
+    }
+}
+
 impl<T: Value> Add<Delta<T>> for Point<T> {
     type Output = Point<T>;
 
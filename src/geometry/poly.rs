@@ -1,9 +1,18 @@
-use std::cmp::min;
+use std::backtrace::Backtrace;
+use std::borrow::Cow;
+use std::cmp::{min, Ordering};
+use std::collections::HashSet;
 
-use decorum::Finite;
-use num_traits::identities::Zero;
+use decorum::{Finite, Real};
+use num_traits::identities::{One, Zero};
+use num_traits::{Signed, ToPrimitive};
 
+use crate::geometry::arc::Arc;
+use crate::geometry::bounds::{Bounded, Rect};
+use crate::geometry::error::*;
 use crate::geometry::line::{Line, LineIntersection};
+use crate::geometry::path::{coincident, Path, PathSegment};
+use crate::geometry::transform::Affine;
 use crate::geometry::*;
 use crate::geometry::{Intersects, Offset};
 
@@ -11,18 +20,1285 @@ use crate::geometry::{Intersects, Offset};
 pub struct Polyline<T: Value>(Vec<Point<T>>);
 
 impl<'a, T: Value> Polyline<T> {
+    // A polyline needs at least two points, and no two consecutive points may coincide.
+    pub fn new(points: Vec<Point<T>>) -> CurvyResult<Self> {
+        if points.len() < 2 {
+            return curvy_err!("Polyline requires at least two points");
+        }
+        for pair in points.windows(2) {
+            Line::new(pair[0], pair[1])?;
+        }
+        Ok(Polyline(points))
+    }
+
+    // Same as Polyline::new, from raw [x, y] coordinates, which must be finite.
+    pub fn from_flat(coordinates: &[[T; 2]]) -> CurvyResult<Self> {
+        Self::new(points_from_flat(coordinates)?)
+    }
+
     pub fn points(&'a self) -> &'a Vec<Point<T>> {
         &self.0
     }
+
+    // Raw [x, y] coordinates of the points, such as for passing over FFI.
+    pub fn to_flat(&self) -> Vec<[T; 2]> {
+        points_to_flat(&self.0)
+    }
+
+    // The segments between consecutive points, in order. Same as iter_segments.
+    pub fn to_segments(&self) -> Vec<Line<T>> {
+        self.iter_segments().collect()
+    }
+
+    pub fn into_segments(self) -> Vec<Line<T>> {
+        self.to_segments()
+    }
+
+    // Transform every point. An identity transformation borrows the polyline rather
+    // than copying it. Fails if the transformation collapses consecutive points together.
+    pub fn transform(&self, transformation: &Affine<T>) -> CurvyResult<Cow<'_, Self>> {
+        if transformation.is_identity() {
+            return Ok(Cow::Borrowed(self));
+        }
+        let points = self.0.iter().map(|&point| transformation.apply(point));
+        Ok(Cow::Owned(Self::new(points.collect())?))
+    }
+
+    // Direction of travel at each point. Interior points take the circular mean of the
+    // directions of the segments before and after them, so a corner's tangent bisects the
+    // turn; the end points take the direction of their only segment. Where the polyline
+    // doubles back on itself the mean is undefined, and the incoming direction is used.
+    pub fn tangent_angles(&self) -> Vec<Angle<T>> {
+        let segment_angles: Vec<Angle<T>> =
+            self.iter_segments().map(|segment| segment.angle).collect();
+        let one = Finite::<T>::one();
+        let mut tangents = vec![segment_angles[0]];
+        for pair in segment_angles.windows(2) {
+            let sum = Delta::magnitude_angle(one, pair[0]) + Delta::magnitude_angle(one, pair[1]);
+            tangents.push(sum.try_angle().unwrap_or(pair[0]));
+        }
+        tangents.push(segment_angles[segment_angles.len() - 1]);
+        tangents
+    }
+
+    // Move each interior point to the mean of the window of points around it, to smooth
+    // out noise such as in a GPS track. The window is narrowed towards the ends so it
+    // stays centered, and the end points don't move. Windows of one point or less leave
+    // the polyline as it is. Points which smoothing brings together are merged.
+    pub fn smooth_moving_average(&self, window: usize) -> Polyline<T> {
+        let n_points = self.0.len();
+        let half_window = window.saturating_sub(1) / 2;
+        let mut points: Vec<Point<T>> = Vec::with_capacity(n_points);
+        points.push(self.0[0]);
+        for index in 1..(n_points - 1) {
+            let reach = half_window.min(index).min(n_points - 1 - index);
+            let neighbors = self.0[(index - reach)..=(index + reach)].iter().copied();
+            let point = Point::centroid(neighbors).unwrap();
+            if Line::new(points[points.len() - 1], point).is_ok() {
+                points.push(point);
+            }
+        }
+        let last = self.0[n_points - 1];
+        if points.len() > 1 && Line::new(points[points.len() - 1], last).is_err() {
+            points.pop();
+        }
+        points.push(last);
+        Polyline(points)
+    }
+
+    // The polyline up to where it first crosses the barrier's boundary, ending at the
+    // crossing, or the whole polyline if it never crosses. Starting on the boundary
+    // doesn't count as crossing it, and where the polyline runs along an edge of the
+    // barrier it crosses only where it meets another edge.
+    pub fn trim_at_boundary(&self, barrier: &Polygon<T>) -> Polyline<T> {
+        let edges = barrier.to_segments();
+        let start = self.0[0];
+        for (index, segment) in self.iter_segments().enumerate() {
+            let segment_start = self.0[index];
+            let crossing = edges
+                .iter()
+                .filter_map(|edge| match segment.intersect(edge) {
+                    | LineIntersection::OnePoint(point) => Some(point),
+                    | _ => None,
+                })
+                .filter(|&point| !coincident(point, start))
+                .min_by_key(|&point| segment_start.distance_squared(point));
+            if let Some(crossing) = crossing {
+                let mut points = self.0[..=index].to_vec();
+                if !coincident(segment_start, crossing) {
+                    points.push(crossing);
+                }
+                return Polyline(points);
+            }
+        }
+        self.clone()
+    }
+
+    // Fewer points tracing nearly the same path, by the Ramer-Douglas-Peucker algorithm.
+    // The end points are kept, and every point left out is within epsilon of the
+    // simplified polyline.
+    pub fn simplify(&self, epsilon: Finite<T>) -> Polyline<T> {
+        let mut points = vec![self.0[0]];
+        douglas_peucker(&self.0, epsilon, &mut points);
+        Polyline(points)
+    }
+
+    // Round off the corners by Chaikin's algorithm, which converges on a quadratic
+    // B-spline. Each iteration cuts every interior point away, leaving the points a
+    // quarter of the way back along the segments either side of it, so n points become
+    // 2n - 2. The end points stay where they are.
+    pub fn smooth(&self, iterations: usize) -> Polyline<T> {
+        let quarter = Finite::<T>::from_inner(T::from_f64(0.25).unwrap());
+        let mut points = self.0.clone();
+        for _ in 0..iterations {
+            let n_points = points.len();
+            let mut cut = Vec::with_capacity(2 * n_points - 2);
+            cut.push(points[0]);
+            for corner in points.windows(3) {
+                cut.push(corner[1].lerp(corner[0], quarter));
+                cut.push(corner[1].lerp(corner[2], quarter));
+            }
+            cut.push(points[n_points - 1]);
+            points = cut;
+        }
+        Polyline(points)
+    }
+
+    // Archimedean spiral around center, starting at angle 0 and winding counterclockwise.
+    // The radius grows by growth_per_turn every full turn (or shrinks, if negative).
+    pub fn spiral(
+        center: Point<T>,
+        start_radius: Finite<T>,
+        growth_per_turn: Finite<T>,
+        turns: usize,
+        points_per_turn: usize,
+    ) -> CurvyResult<Self> {
+        if turns < 1 || points_per_turn < 3 {
+            return curvy_err!("Spiral requires a turn of at least three points");
+        }
+        let n_points = turns * points_per_turn + 1;
+        let points_per_turn =
+            Finite::<T>::from_inner(T::from_usize(points_per_turn).unwrap());
+        let two_pi = T::tau();
+        let points = (0..n_points)
+            .map(|index| {
+                let index = Finite::<T>::from_inner(T::from_usize(index).unwrap());
+                let turns = index / points_per_turn;
+                let radius = start_radius + growth_per_turn * turns;
+                let angle = Angle::from(AngleDiff(turns * two_pi));
+                center + Delta::magnitude_angle(radius, angle)
+            })
+            .collect();
+        Polyline::new(points)
+    }
 }
 
 #[derive(Clone, Debug)]
 pub struct Polygon<T: Value>(Vec<Point<T>>);
 
 impl<'a, T: Value> Polygon<T> {
+    // A polygon needs at least three points, and no two consecutive points may coincide,
+    // including the last and first points.
+    pub fn new(points: Vec<Point<T>>) -> CurvyResult<Self> {
+        let n_points = points.len();
+        if n_points < 3 {
+            return curvy_err!("Polygon requires at least three points");
+        }
+        for (index, point) in points.iter().enumerate() {
+            Line::new(*point, points[(index + 1) % n_points])?;
+        }
+        Ok(Polygon(points))
+    }
+
+    // Same as Polygon::new, from raw [x, y] coordinates, which must be finite.
+    pub fn from_flat(coordinates: &[[T; 2]]) -> CurvyResult<Self> {
+        Self::new(points_from_flat(coordinates)?)
+    }
+
+    // Same as Polygon::new, but reverses clockwise points so that the polygon always
+    // winds counterclockwise. A positive offset then always outsets the polygon.
+    pub fn new_ccw(points: Vec<Point<T>>) -> CurvyResult<Self> {
+        Ok(Self::new(points)?.as_ccw())
+    }
+
+    // Regular polygon with its corners on the circle of radius about center, winding
+    // counterclockwise from the first corner at angle 0.
+    pub fn from_regular(
+        center: Point<T>,
+        radius: Finite<T>,
+        sides: usize,
+    ) -> CurvyResult<Self> {
+        if sides < 3 {
+            return curvy_err!("Regular polygon requires at least three sides");
+        }
+        if radius <= Finite::<T>::zero() {
+            return curvy_err!("Regular polygon radius must be positive");
+        }
+        let step = T::tau() / Finite::<T>::from_inner(T::from_usize(sides).unwrap());
+        let corners = (0..sides)
+            .map(|index| {
+                let index = Finite::<T>::from_inner(T::from_usize(index).unwrap());
+                Point::polar_about(center, radius, Angle::from(AngleDiff(step * index)))
+            })
+            .collect();
+        Polygon::new(corners)
+    }
+
+    // Axis-aligned rectangle extending width along x and height along y from corner,
+    // winding counterclockwise from corner.
+    pub fn from_rectangle(
+        corner: Point<T>,
+        width: Finite<T>,
+        height: Finite<T>,
+    ) -> CurvyResult<Self> {
+        let zero = Finite::<T>::zero();
+        if width <= zero || height <= zero {
+            return curvy_err!("Rectangle width and height must be positive");
+        }
+        let across = Delta { dx: width, dy: zero };
+        let up = Delta { dx: zero, dy: height };
+        Polygon::new(vec![corner, corner + across, corner + across + up, corner + up])
+    }
+
+    // The same as from_regular, for when the polygon stands in for a circle; more points
+    // follow the circle more closely.
+    pub fn from_circle_approximation(
+        center: Point<T>,
+        radius: Finite<T>,
+        n: usize,
+    ) -> CurvyResult<Self> {
+        Self::from_regular(center, radius, n)
+    }
+
+    // Star around center with the given number of points, winding counterclockwise. The
+    // points reach out to outer_radius, with the corners between them at inner_radius,
+    // and the first point is at start_angle from the center.
+    pub fn star(
+        center: Point<T>,
+        outer_radius: Finite<T>,
+        inner_radius: Finite<T>,
+        points: usize,
+        start_angle: Angle<T>,
+    ) -> CurvyResult<Self> {
+        let zero = Finite::<T>::zero();
+        if points < 3 {
+            return curvy_err!("Star requires at least three points");
+        }
+        if inner_radius <= zero || outer_radius <= inner_radius {
+            return curvy_err!("Star requires 0 < inner_radius < outer_radius");
+        }
+        let n_corners = Finite::<T>::from_inner(T::from_usize(2 * points).unwrap());
+        let step = T::tau() / n_corners;
+        let corners = (0..(2 * points))
+            .map(|index| {
+                let radius = if index.is_multiple_of(2) {
+                    outer_radius
+                } else {
+                    inner_radius
+                };
+                let index = Finite::<T>::from_inner(T::from_usize(index).unwrap());
+                let angle = start_angle + AngleDiff(step * index);
+                center + Delta::magnitude_angle(radius, angle)
+            })
+            .collect();
+        Polygon::new(corners)
+    }
+
+    // Gear around center with square teeth, winding counterclockwise. The teeth stand
+    // tooth_depth proud of the gaps between them, half above and half below
+    // pitch_radius, and are as wide as the gaps at the pitch radius. The first tooth
+    // starts at angle 0.
+    pub fn gear(
+        center: Point<T>,
+        pitch_radius: Finite<T>,
+        teeth: usize,
+        tooth_depth: Finite<T>,
+    ) -> CurvyResult<Self> {
+        let zero = Finite::<T>::zero();
+        let two = Finite::<T>::one() + Finite::<T>::one();
+        if teeth < 3 {
+            return curvy_err!("Gear requires at least three teeth");
+        }
+        let root_radius = pitch_radius - tooth_depth / two;
+        let tip_radius = pitch_radius + tooth_depth / two;
+        if tooth_depth <= zero || root_radius <= zero {
+            return curvy_err!("Gear requires 0 < tooth_depth < 2 * pitch_radius");
+        }
+        let n_edges = Finite::<T>::from_inner(T::from_usize(2 * teeth).unwrap());
+        let step = T::tau() / n_edges;
+        let mut corners = Vec::with_capacity(4 * teeth);
+        for index in 0..(2 * teeth) {
+            let angle = Angle::from(AngleDiff(
+                step * Finite::<T>::from_inner(T::from_usize(index).unwrap()),
+            ));
+            // Up the leading flank of each tooth, and down the trailing one
+            let (first, second) = if index.is_multiple_of(2) {
+                (root_radius, tip_radius)
+            } else {
+                (tip_radius, root_radius)
+            };
+            corners.push(center + Delta::magnitude_angle(first, angle));
+            corners.push(center + Delta::magnitude_angle(second, angle));
+        }
+        Polygon::new(corners)
+    }
+
+    // The same polygon with coordinates of another type, as for Point::cast. Fails if
+    // rounding brings neighboring points together.
+    pub fn cast<U: Value>(&self) -> CurvyResult<Polygon<U>> {
+        let points: CurvyResult<Vec<Point<U>>> =
+            self.0.iter().map(|point| point.cast()).collect();
+        Polygon::new(points?)
+    }
+
+    // A copy of the polygon winding counterclockwise.
+    pub fn as_ccw(&self) -> Self {
+        match self.orientation() {
+            | Direction::Clockwise => self.reversed(),
+            | _ => self.clone(),
+        }
+    }
+
+    // A copy of the polygon winding clockwise.
+    pub fn as_cw(&self) -> Self {
+        match self.orientation() {
+            | Direction::Counterclockwise => self.reversed(),
+            | _ => self.clone(),
+        }
+    }
+
+    fn reversed(&self) -> Self {
+        Polygon(self.0.iter().rev().copied().collect())
+    }
+
     pub fn points(&'a self) -> &'a Vec<Point<T>> {
         &self.0
     }
+
+    // Raw [x, y] coordinates of the points, such as for passing over FFI.
+    pub fn to_flat(&self) -> Vec<[T; 2]> {
+        points_to_flat(&self.0)
+    }
+
+    // The segments between consecutive points, in order, ending with the one from the
+    // last point back to the first. Same as iter_segments.
+    pub fn to_segments(&self) -> Vec<Line<T>> {
+        self.iter_segments().collect()
+    }
+
+    pub fn into_segments(self) -> Vec<Line<T>> {
+        self.to_segments()
+    }
+
+    // Transform every point. An identity transformation borrows the polygon rather
+    // than copying it. Fails if the transformation collapses consecutive points together.
+    pub fn transform(&self, transformation: &Affine<T>) -> CurvyResult<Cow<'_, Self>> {
+        if transformation.is_identity() {
+            return Ok(Cow::Borrowed(self));
+        }
+        let points = self.0.iter().map(|&point| transformation.apply(point));
+        Ok(Cow::Owned(Self::new(points.collect())?))
+    }
+
+    // Project a point onto the nearest location on the polygon's boundary. Returns the
+    // projected point, the index of the edge it lies on (edge i runs from point i to
+    // point i + 1), and the distance from the given point to the projected point.
+    pub fn project_point(&self, point: Point<T>) -> (Point<T>, usize, Finite<T>) {
+        let mut nearest: Option<(Point<T>, usize, Finite<T>)> = None;
+        for (index, line) in self.iter_segments().enumerate() {
+            let projected = line.nearest_point(point);
+            let distance = point.distance(projected);
+            match nearest {
+                | Some((_, _, nearest_distance)) if nearest_distance <= distance => {}
+                | _ => nearest = Some((projected, index, distance)),
+            }
+        }
+        // Polygons always have edges
+        nearest.unwrap()
+    }
+
+    // The best place on the polygon to snap a cursor to, if any is within the radius of
+    // the point. Vertices come first, then the midpoints of edges, then the nearest point
+    // on any edge; among targets of the same kind, the nearest is taken.
+    pub fn snap(&self, point: Point<T>, radius: Finite<T>) -> Option<SnapTarget<T>> {
+        let nearest_within = |candidates: &mut dyn Iterator<Item = (usize, Point<T>)>| {
+            candidates
+                .map(|(index, candidate)| (index, candidate, point.distance(candidate)))
+                .filter(|&(_, _, distance)| distance <= radius)
+                .min_by_key(|&(_, _, distance)| distance)
+        };
+        let n_points = self.0.len();
+        let mut vertices = self.0.iter().copied().enumerate();
+        if let Some((index, _, _)) = nearest_within(&mut vertices) {
+            return Some(SnapTarget::Vertex(index));
+        }
+        let mut midpoints = (0..n_points)
+            .map(|index| (index, self.0[index].midpoint(self.0[(index + 1) % n_points])));
+        if let Some((index, _, _)) = nearest_within(&mut midpoints) {
+            return Some(SnapTarget::EdgeMidpoint(index));
+        }
+        let (projected, index, distance) = self.project_point(point);
+        (distance <= radius).then_some(SnapTarget::OnEdge(index, projected))
+    }
+
+    // Area by the shoelace formula. This is positive when the points wind counterclockwise,
+    // and negative when they wind clockwise.
+    pub fn signed_area(&self) -> Finite<T> {
+        let two = T::two();
+        let n_points = self.0.len();
+        let mut sum = Finite::<T>::zero();
+        for (index, point) in self.0.iter().enumerate() {
+            let next = self.0[(index + 1) % n_points];
+            sum = sum + point.x * next.y - next.x * point.y;
+        }
+        sum / two
+    }
+
+    // Area enclosed, whichever way the points wind.
+    pub fn area(&self) -> Finite<T> {
+        self.signed_area().abs()
+    }
+
+    pub fn is_counterclockwise(&self) -> bool {
+        self.orientation() == Direction::Counterclockwise
+    }
+
+    pub fn is_clockwise(&self) -> bool {
+        self.orientation() == Direction::Clockwise
+    }
+
+    // Centroid of the area, rather than the mean of the points, which is pulled towards
+    // wherever the points are closest together. It's calculated relative to the first
+    // point so that the result stays accurate for polygons far from the origin. A
+    // polygon with no area, such as one whose points are all in a line, has no centroid
+    // of its area, and gives the mean of its points instead.
+    pub fn centroid(&self) -> Point<T> {
+        let three = Finite::<T>::from_inner(T::from_f64(3.0).unwrap());
+        let first = self.0[0];
+        let n_points = self.0.len();
+        let mut twice_area = Finite::<T>::zero();
+        let mut sum = Delta {
+            dx: Finite::<T>::zero(),
+            dy: Finite::<T>::zero(),
+        };
+        for index in 0..n_points {
+            let a = self.0[index] - first;
+            let b = self.0[(index + 1) % n_points] - first;
+            let cross = a.cross(b);
+            twice_area += cross;
+            sum = sum + (a + b) * cross;
+        }
+        if twice_area == Finite::<T>::zero() {
+            return Point::centroid(self.0.iter().copied()).unwrap();
+        }
+        first + sum / (three * twice_area)
+    }
+
+    // A copy translated so that its centroid is at the origin, along with the translation
+    // which was applied; subtract it to translate results back. Line keeps its position
+    // relative to the origin, so operations like offset and intersect are more accurate
+    // for geometry near the origin than for geometry far from it. Polygons with no area
+    // are centered on the mean of their points, as for centroid.
+    pub fn recenter(&self) -> (Polygon<T>, Delta<T>) {
+        let translation = Point::origin() - self.centroid();
+        let points = self.0.iter().map(|&point| point + translation).collect();
+        (Polygon(points), translation)
+    }
+
+    pub fn orientation(&self) -> Direction {
+        match self.signed_area().cmp(&Finite::<T>::zero()) {
+            | Ordering::Equal => Direction::None,
+            | Ordering::Greater => Direction::Counterclockwise,
+            | Ordering::Less => Direction::Clockwise,
+        }
+    }
+
+    // Whether offsetting by the given amount will grow or shrink the polygon. Positive
+    // offsets outset counterclockwise polygons and inset clockwise ones, so a polygon
+    // wound the wrong way does the opposite of what was meant without complaint; this
+    // lets that be checked before offsetting. Ambiguous for a zero offset, or for a
+    // polygon with no area and so no winding.
+    pub fn check_offset_orientation(&self, offset: Finite<T>) -> OffsetHint {
+        let zero = Finite::<T>::zero();
+        if offset == zero {
+            return OffsetHint::Ambiguous;
+        }
+        match (self.orientation(), offset > zero) {
+            | (Direction::None, _) => OffsetHint::Ambiguous,
+            | (Direction::Counterclockwise, true) | (Direction::Clockwise, false) => {
+                OffsetHint::WillGrow
+            }
+            | (Direction::Counterclockwise, false) | (Direction::Clockwise, true) => {
+                OffsetHint::WillShrink
+            }
+        }
+    }
+
+    // Offset the polygon outwards or inwards until it has the target area, finding the
+    // offset by bisection. Fails if the polygon collapses before shrinking to the target.
+    pub fn inflate_to_area(&self, target_area: Finite<T>) -> CurvyResult<Polygon<T>> {
+        let zero = Finite::<T>::zero();
+        let two = T::two();
+        if target_area <= zero {
+            return curvy_err!("Target area must be positive");
+        }
+        // Positive offsets outset counterclockwise polygons, but inset clockwise ones.
+        let sign = match self.orientation() {
+            | Direction::Counterclockwise => Finite::<T>::one(),
+            | Direction::Clockwise => -Finite::<T>::one(),
+            | Direction::None => return curvy_err!("Polygon has no area"),
+        };
+        // Outset by the given distance, with its area, or None if it collapsed or
+        // turned inside out.
+        let inflated = |distance: Finite<T>| -> Option<(Polygon<T>, Finite<T>)> {
+            let polygon = self.clone().offset(distance * sign).ok()?;
+            let area = polygon.signed_area() * sign;
+            if area > zero {
+                Some((polygon, area))
+            } else {
+                None
+            }
+        };
+        let tolerance = target_area * Finite::<T>::from_inner(T::EPSILON.sqrt());
+        let area = self.signed_area() * sign;
+        if (area - target_area).abs() < tolerance {
+            return Ok(self.clone());
+        }
+        let (mut low, mut high) = if target_area > area {
+            // Outsetting can't collapse, so keep doubling until the area is big enough.
+            let mut high = area.sqrt();
+            for _ in 0..MAX_ITERATIONS {
+                match inflated(high) {
+                    | Some((_, area)) if area < target_area => high *= two,
+                    | _ => break,
+                }
+            }
+            (zero, high)
+        } else {
+            // Nothing can be inset further than the radius of a circle of the same
+            // area, though thin polygons collapse well before; insets that collapse
+            // bisect back towards zero.
+            (-(area / Finite::<T>::PI).sqrt(), zero)
+        };
+        for _ in 0..MAX_ITERATIONS {
+            let middle = (low + high) / two;
+            match inflated(middle) {
+                | Some((polygon, area)) if (area - target_area).abs() < tolerance => {
+                    return Ok(polygon);
+                }
+                | Some((_, area)) if area > target_area => high = middle,
+                // Too small, or collapsed
+                | _ => low = middle,
+            }
+        }
+        curvy_err!("Polygon collapses before reaching the target area")
+    }
+
+    // Split into triangles by ear clipping. The polygon must be simple. Triangles wind
+    // counterclockwise regardless of the orientation of the polygon.
+    pub fn triangulate(&self) -> CurvyResult<Vec<Polygon<T>>> {
+        Ok(self
+            .triangulate_indices()?
+            .iter()
+            .map(|triangle| self.select(triangle))
+            .collect())
+    }
+
+    // Decompose into convex polygons by the Hertel-Mehlhorn algorithm: triangulate, then
+    // remove any diagonal between two pieces which leaves their union convex. This gives
+    // at most four times the minimum number of convex pieces, and usually far fewer
+    // pieces than triangulation. Pieces wind counterclockwise.
+    pub fn decompose_convex(&self) -> CurvyResult<Vec<Polygon<T>>> {
+        let mut pieces: Vec<Vec<usize>> = self
+            .triangulate_indices()?
+            .iter()
+            .map(|triangle| triangle.to_vec())
+            .collect();
+        'merging: loop {
+            for i in 0..pieces.len() {
+                for j in (i + 1)..pieces.len() {
+                    if let Some(merged) = merge_pieces(&pieces[i], &pieces[j]) {
+                        if self.is_convex_loop(&merged) {
+                            pieces[i] = merged;
+                            pieces.swap_remove(j);
+                            continue 'merging;
+                        }
+                    }
+                }
+            }
+            break;
+        }
+        Ok(pieces.iter().map(|piece| self.select(piece)).collect())
+    }
+
+    // Every point where an edge of this polygon crosses an edge of the other, with the
+    // index of each edge; edge i runs from point i to point i + 1. Edges which only
+    // overlap along a stretch, rather than crossing at a point, are left out.
+    pub fn boundary_intersections(
+        &self,
+        other: &Polygon<T>,
+    ) -> Vec<(usize, usize, Point<T>)> {
+        if !self.may_intersect(other) {
+            return Vec::new();
+        }
+        let other_edges = other.to_segments();
+        let mut crossings = Vec::new();
+        for (self_index, edge) in self.iter_segments().enumerate() {
+            for (other_index, other_edge) in other_edges.iter().enumerate() {
+                if let LineIntersection::OnePoint(point) = edge.intersect(other_edge) {
+                    crossings.push((self_index, other_index, point));
+                }
+            }
+        }
+        crossings
+    }
+
+    // Cheap test for whether the polygons could overlap, before finding how they do. This
+    // is false only if their bounding boxes or bounding circles are apart, so the polygons
+    // can't overlap, but may be true for polygons which don't.
+    pub fn may_intersect(&self, other: &Polygon<T>) -> bool {
+        if !self.bounding_box().intersects(&other.bounding_box()) {
+            return false;
+        }
+        let (center, radius) = self.bounding_circle();
+        let (other_center, other_radius) = other.bounding_circle();
+        center.distance(other_center) <= radius + other_radius
+    }
+
+    // A circle around every point, centered on the bounding box. It isn't the smallest
+    // such circle, but is quick to find.
+    fn bounding_circle(&self) -> (Point<T>, Finite<T>) {
+        let center = self.bounding_box().center();
+        let radius = self
+            .0
+            .iter()
+            .map(|&point| center.distance(point))
+            .fold(Finite::<T>::zero(), |radius, distance| radius.max(distance));
+        (center, radius)
+    }
+
+    // The region inside both polygons, as convex pieces which wind counterclockwise. Both
+    // polygons must be simple. Each is split into convex pieces, and every pair of pieces
+    // is clipped one against the other. Returns no pieces if the polygons don't overlap.
+    pub fn intersection(&self, other: &Polygon<T>) -> CurvyResult<Vec<Polygon<T>>> {
+        if !self.may_intersect(other) {
+            return Ok(Vec::new());
+        }
+        let zero = Finite::<T>::zero();
+        let other_pieces = other.decompose_convex()?;
+        let mut pieces = Vec::new();
+        for piece in self.decompose_convex()? {
+            for other_piece in other_pieces.iter() {
+                let mut points = clip_convex(&piece.0, &other_piece.0);
+                points.dedup_by(|a, b| coincident(*a, *b));
+                while points.len() > 1 && coincident(points[0], *points.last().unwrap()) {
+                    points.pop();
+                }
+                // Pieces which only touch leave nothing, or nothing with area.
+                if let Ok(clipped) = Polygon::new(points) {
+                    if clipped.signed_area() > zero {
+                        pieces.push(clipped);
+                    }
+                }
+            }
+        }
+        Ok(pieces)
+    }
+
+    // Area of the region inside both polygons, which is zero if they don't overlap.
+    pub fn overlap_area(&self, other: &Polygon<T>) -> CurvyResult<Finite<T>> {
+        Ok(self
+            .intersection(other)?
+            .iter()
+            .fold(Finite::<T>::zero(), |area, piece| area + piece.signed_area()))
+    }
+
+    // Areas below this are rounding error for loops split from the polygon, relative to
+    // the square of its size.
+    fn area_tolerance(&self) -> Finite<T> {
+        let bounds = self.bounding_box();
+        let size = bounds.width().max(bounds.height());
+        size * size * Finite::<T>::from_inner(T::EPSILON.sqrt())
+    }
+
+    // Split a loop which crosses or touches itself into simple rings, nested by
+    // containment. Each ring which isn't inside another, or is inside a hole, is given as
+    // an outer ring, counterclockwise, with the rings directly inside it as holes,
+    // clockwise. Loops with no area, such as where the polygon doubles back on itself,
+    // are left out.
+    pub fn to_rings(&self) -> CurvyResult<Vec<PolygonWithHoles<T>>> {
+        let tolerance = self.area_tolerance();
+        let mut rings: Vec<Polygon<T>> = Vec::new();
+        for points in split_loops(self.with_crossings()) {
+            if points.len() < 3 {
+                continue;
+            }
+            let ring = Polygon::new(points)?;
+            if ring.signed_area().abs() > tolerance {
+                rings.push(ring);
+            }
+        }
+        // Larger rings first, so that any ring containing another comes before it
+        rings.sort_by_key(|ring| -ring.signed_area().abs());
+
+        // Parent of each ring, the smallest ring containing it, and its depth of nesting.
+        let mut parents: Vec<Option<usize>> = Vec::with_capacity(rings.len());
+        let mut depths: Vec<usize> = Vec::with_capacity(rings.len());
+        for (index, ring) in rings.iter().enumerate() {
+            let parent = (0..index).rev().find(|&other| rings[other].encloses(ring));
+            depths.push(parent.map_or(0, |parent| depths[parent] + 1));
+            parents.push(parent);
+        }
+
+        Ok((0..rings.len())
+            .filter(|&index| depths[index].is_multiple_of(2))
+            .map(|index| PolygonWithHoles {
+                outer: rings[index].as_ccw(),
+                holes: (0..rings.len())
+                    .filter(|&hole| parents[hole] == Some(index))
+                    .map(|hole| rings[hole].as_cw())
+                    .collect(),
+            })
+            .collect())
+    }
+
+    // Same as Polyline::simplify, around the closed loop. The loop is split at the first
+    // point and the point farthest from it, which are both kept. A polygon within
+    // epsilon of a line keeps a third point, the one farthest from that line.
+    pub fn simplify(&self, epsilon: Finite<T>) -> Polygon<T> {
+        let first = self.0[0];
+        let (far, _) = self
+            .0
+            .iter()
+            .enumerate()
+            .max_by_key(|&(_, &point)| first.distance_squared(point))
+            .unwrap();
+        let mut there = self.0[..=far].to_vec();
+        let mut back = self.0[far..].to_vec();
+        back.push(first);
+        let mut points = vec![first];
+        douglas_peucker(&there, epsilon, &mut points);
+        douglas_peucker(&back, epsilon, &mut points);
+        // Back at the first point
+        points.pop();
+        if points.len() < 3 {
+            let chord = Line::new(first, self.0[far]).unwrap();
+            there.pop();
+            back.pop();
+            let farthest = |side: &[Point<T>]| {
+                side.iter()
+                    .map(|&point| (chord.distance_to_point(point), point))
+                    .max_by_key(|&(distance, _)| distance)
+            };
+            match (farthest(&there[1..]), farthest(&back[1..])) {
+                | (Some(there), Some(back)) if back.0 > there.0 => points.push(back.1),
+                | (Some(there), _) => points.insert(1, there.1),
+                | (None, back) => points.push(back.unwrap().1),
+            }
+        }
+        Polygon(points)
+    }
+
+    // Same as Polyline::smooth, around the closed loop, so every point is cut away and
+    // n points become 2n.
+    pub fn smooth(&self, iterations: usize) -> Polygon<T> {
+        let quarter = Finite::<T>::from_inner(T::from_f64(0.25).unwrap());
+        let mut points = self.0.clone();
+        for _ in 0..iterations {
+            let n_points = points.len();
+            let mut cut = Vec::with_capacity(2 * n_points);
+            for (index, &point) in points.iter().enumerate() {
+                let prev = points[(index + n_points - 1) % n_points];
+                let next = points[(index + 1) % n_points];
+                cut.push(point.lerp(prev, quarter));
+                cut.push(point.lerp(next, quarter));
+            }
+            points = cut;
+        }
+        Polygon(points)
+    }
+
+    // Inset by distance, as morphological erosion: where the polygon pinches off as it
+    // shrinks, such as across the neck of a dumbbell, the pieces either side are given
+    // as separate polygons, each winding counterclockwise.
+    pub fn inset_split(self, distance: Finite<T>) -> CurvyResult<Vec<Polygon<T>>> {
+        if distance < Finite::<T>::zero() {
+            return curvy_err!("Inset distance must not be negative");
+        }
+        if self.orientation() == Direction::None {
+            return curvy_err!("Polygon has no area");
+        }
+        // Positive offsets outset counterclockwise polygons. Nothing is left of a
+        // polygon inset until it collapses.
+        let inset = match self.as_ccw().offset(-distance) {
+            | Ok(inset) => inset,
+            | Err(error) if error.kind == ErrorKind::Collapsed => return Ok(Vec::new()),
+            | Err(error) => return Err(error),
+        };
+        // Past a pinch, the inset crosses itself. The loops either side still wind
+        // counterclockwise, while the loop between them has turned inside out.
+        let tolerance = self.area_tolerance();
+        let mut pieces = Vec::new();
+        for points in split_loops(inset.with_crossings()) {
+            // Loops with coincident points have collapsed, as have those with no area
+            let piece = match Polygon::new(points) {
+                | Ok(piece) => piece,
+                | Err(_) => continue,
+            };
+            if piece.signed_area() > tolerance {
+                pieces.push(piece);
+            }
+        }
+        Ok(pieces)
+    }
+
+    // Approximate the medial axis (the skeleton, made of points with more than one
+    // nearest point on the boundary) from samples on a square grid with the given
+    // spacing. A sample inside the polygon is on a ridge where its distance from the
+    // boundary is greatest along a row or column of the grid; neighboring ridge samples
+    // are joined into polylines. The result is within about one spacing of the true
+    // medial axis, and branches which are shorter than that may be missing. The spacing
+    // must be positive, and not so small that the grid can't be counted.
+    pub fn approximate_medial_axis(
+        &self,
+        sample_spacing: Finite<T>,
+    ) -> CurvyResult<Vec<Polyline<T>>> {
+        let zero = Finite::<T>::zero();
+        let half = T::half();
+        if sample_spacing <= zero {
+            return curvy_err!("Sample spacing must be positive");
+        }
+        // Polygons always have points
+        let bounds = self.bounding_box();
+        let count = |extent: Finite<T>| (extent / sample_spacing).ceil().to_usize();
+        let (columns, rows) = match (count(bounds.width()), count(bounds.height())) {
+            | (Some(columns), Some(rows)) => (columns, rows),
+            | _ => return curvy_err!("Sample spacing is too small for the polygon"),
+        };
+        let sample = |column: usize, row: usize| Point {
+            x: bounds.min.x
+                + (Finite::<T>::from_inner(T::from_usize(column).unwrap()) + half)
+                    * sample_spacing,
+            y: bounds.min.y
+                + (Finite::<T>::from_inner(T::from_usize(row).unwrap()) + half)
+                    * sample_spacing,
+        };
+
+        // Signed distance from the boundary, positive inside.
+        let distances: Vec<Vec<Finite<T>>> = (0..columns)
+            .map(|column| {
+                (0..rows)
+                    .map(|row| {
+                        let point = sample(column, row);
+                        let (_, _, distance) = self.project_point(point);
+                        if self.winding_number(point) != 0 {
+                            distance
+                        } else {
+                            -distance
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        // Samples beyond the grid are outside.
+        let distance_at = |column: isize, row: isize| {
+            let beyond = column < 0 || row < 0;
+            if beyond || column as usize >= columns || row as usize >= rows {
+                -sample_spacing
+            } else {
+                distances[column as usize][row as usize]
+            }
+        };
+
+        // A sample is a peak along a line of samples if it rises from the sample before
+        // and doesn't fall to the sample after, or if it falls to the sample after at the
+        // end of a level stretch. Where the axis falls between two samples, and so both
+        // have the greatest distance, only the first is taken.
+        // Distances are only level to within rounding error.
+        let tolerance = sample_spacing * Finite::<T>::from_inner(T::EPSILON.sqrt());
+        let above = |a: Finite<T>, b: Finite<T>| a - b > tolerance;
+        let is_peak = |before_previous: Finite<T>,
+                       previous: Finite<T>,
+                       distance: Finite<T>,
+                       next: Finite<T>| {
+            let rises = above(distance, previous) && !above(next, distance);
+            let falls = !above(previous, distance)
+                && above(distance, next)
+                && (above(distance, previous) || !above(previous, before_previous));
+            rises || falls
+        };
+        let mut ridge = HashSet::new();
+        for column in 0..columns as isize {
+            for row in 0..rows as isize {
+                let distance = distance_at(column, row);
+                if distance <= zero {
+                    continue;
+                }
+                let along_row = is_peak(
+                    distance_at(column - 2, row),
+                    distance_at(column - 1, row),
+                    distance,
+                    distance_at(column + 1, row),
+                );
+                let along_column = is_peak(
+                    distance_at(column, row - 2),
+                    distance_at(column, row - 1),
+                    distance,
+                    distance_at(column, row + 1),
+                );
+                if along_row || along_column {
+                    ridge.insert((column, row));
+                }
+            }
+        }
+
+        Ok(trace_cells(&ridge)
+            .iter()
+            .filter_map(|cells| {
+                let points = cells
+                    .iter()
+                    .map(|&(column, row)| sample(column as usize, row as usize))
+                    .collect();
+                Polyline::new(points).ok()
+            })
+            .collect())
+    }
+
+    // Offset the polygon with round joins: where offsetting opens a gap at a corner, as at
+    // the convex corners of an outset, the gap is filled by an arc of radius offset about
+    // the original corner. Other corners stay sharp. The offset must be small enough that
+    // no edge vanishes.
+    pub fn offset_rounded(self, offset: Finite<T>) -> CurvyResult<Polycurve<T>> {
+        let zero = Finite::<T>::zero();
+        let lines: Vec<Line<T>> = self.iter_segments().collect();
+        let n_lines = lines.len();
+        let mut corners = Vec::with_capacity(n_lines);
+        let mut curve_sizes = Vec::with_capacity(n_lines);
+        // Corner i is between edge i - 1 and edge i.
+        for (index, line) in lines.iter().enumerate() {
+            let prev_line = lines[(index + n_lines - 1) % n_lines];
+            let prev_offset = prev_line.offset(offset);
+            let offset_line = line.offset(offset);
+            let prev_direction = prev_line.stop() - prev_line.start();
+            let turn = prev_direction.cross(line.stop() - line.start());
+            if turn == zero {
+                corners.push(offset_line.start());
+                curve_sizes.push(zero);
+                continue;
+            }
+            let corner = match offset_line.intersect(&prev_offset) {
+                | LineIntersection::OnePoint(point)
+                | LineIntersection::OutOfBounds(point) => point,
+                | _ => return curvy_err!("Polygon turns back on itself at a corner"),
+            };
+            corners.push(corner);
+            // A positive offset moves to the right, opening a gap at left turns.
+            if (turn > zero) == (offset > zero) {
+                curve_sizes.push(corner.distance(offset_line.start()));
+            } else {
+                curve_sizes.push(zero);
+            }
+        }
+        // Each offset edge must be long enough for the arcs at both of its ends.
+        for (index, line) in lines.iter().enumerate() {
+            let next = (index + 1) % n_lines;
+            let edge = corners[next] - corners[index];
+            let along = edge.rotate(-line.angle).dx;
+            if along < curve_sizes[index] + curve_sizes[next] {
+                return curvy_err!("Offset is too large for the polygon");
+            }
+        }
+        Ok(Polycurve {
+            polygon: Polygon::new(corners)?,
+            curve_sizes,
+        })
+    }
+
+    // Scale and move the polygon so that its bounding box fits in the middle of the target.
+    // Keeping the aspect ratio, the polygon fills the target in only one direction;
+    // otherwise it is stretched to fill the target in both directions.
+    pub fn fit_into(&self, target: Rect<T>, preserve_aspect: bool) -> CurvyResult<Polygon<T>> {
+        let zero = Finite::<T>::zero();
+        // Polygons always have points
+        let bounds = self.bounding_box();
+        if bounds.width() == zero || bounds.height() == zero {
+            return curvy_err!("Polygon has no area to scale");
+        }
+        let scale_x = target.width() / bounds.width();
+        let scale_y = target.height() / bounds.height();
+        let (scale_x, scale_y) = if preserve_aspect {
+            let scale = scale_x.min(scale_y);
+            (scale, scale)
+        } else {
+            (scale_x, scale_y)
+        };
+        let (from, to) = (bounds.center(), target.center());
+        Polygon::new(
+            self.0
+                .iter()
+                .map(|&point| Point {
+                    x: to.x + (point.x - from.x) * scale_x,
+                    y: to.y + (point.y - from.y) * scale_y,
+                })
+                .collect(),
+        )
+    }
+
+    // Whether the point is inside the polygon, by its winding number. Points exactly on
+    // the boundary may be counted as either inside or outside; use contains_point()
+    // where they must count as inside.
+    pub fn contains(&self, point: Point<T>) -> bool {
+        self.winding_number(point) != 0
+    }
+
+    // Whether the point is inside the polygon or on its boundary, including at its
+    // corners. Unlike contains(), points on the boundary are found by their distance from
+    // each edge, allowing for rounding error, which makes this slower.
+    pub fn contains_point(&self, point: Point<T>) -> bool {
+        let on_boundary = self
+            .iter_segments()
+            .any(|edge| coincident(edge.nearest_point(point), point));
+        on_boundary || self.winding_number(point) != 0
+    }
+
+    // Whether the polygon is convex: it is simple, and never turns both ways. Points at
+    // which it doesn't turn are allowed.
+    pub fn is_convex(&self) -> bool {
+        let zero = Finite::<T>::zero();
+        let n_points = self.0.len();
+        let mut turned_left = false;
+        let mut turned_right = false;
+        let mut total_turn = zero;
+        for index in 0..n_points {
+            let prev = (index + n_points - 1) % n_points;
+            let next = (index + 1) % n_points;
+            match self.turn(prev, index, next).cmp(&zero) {
+                | Ordering::Greater => turned_left = true,
+                | Ordering::Less => turned_right = true,
+                | Ordering::Equal => {}
+            }
+            let incoming = (self.0[index] - self.0[prev]).angle();
+            let outgoing = (self.0[next] - self.0[index]).angle();
+            total_turn += (outgoing - incoming).radians();
+        }
+        // Turning one way throughout, a star polygon turns around more than once
+        !(turned_left && turned_right) && total_turn.abs() < T::tau() + T::pi()
+    }
+
+    // Smallest convex polygon containing every point, winding counterclockwise, by
+    // Andrew's monotone chain algorithm. A polygon which is already convex is returned
+    // as it is, apart from its winding; otherwise points along the hull's edges are left
+    // out. Fails if the points are all in a line, as the hull has no area.
+    pub fn convex_hull(&self) -> CurvyResult<Polygon<T>> {
+        if self.is_convex() && self.orientation() != Direction::None {
+            return Ok(self.as_ccw());
+        }
+        let zero = Finite::<T>::zero();
+        let mut points = self.0.clone();
+        points.sort();
+        points.dedup();
+        // Build one side of the hull, turning left at every point
+        let chain = |points: &mut dyn Iterator<Item = &Point<T>>| {
+            let mut chain: Vec<Point<T>> = Vec::new();
+            for &point in points {
+                while chain.len() >= 2 {
+                    let last = chain[chain.len() - 1];
+                    let before = chain[chain.len() - 2];
+                    if (last - before).cross(point - last) > zero {
+                        break;
+                    }
+                    chain.pop();
+                }
+                chain.push(point);
+            }
+            // The last point starts the other side
+            chain.pop();
+            chain
+        };
+        let mut hull = chain(&mut points.iter());
+        hull.extend(chain(&mut points.iter().rev()));
+        if hull.len() < 3 {
+            return curvy_err!("Convex hull of collinear points has no area");
+        }
+        Ok(Polygon(hull))
+    }
+
+    // The points, with the points where edges cross added to both edges, so that the
+    // loop passes through each crossing twice.
+    fn with_crossings(&self) -> Vec<Point<T>> {
+        let edges = self.to_segments();
+        let n_edges = edges.len();
+        let mut splits: Vec<Vec<Point<T>>> = vec![Vec::new(); n_edges];
+        for i in 0..n_edges {
+            // Neighboring edges only meet at their shared point
+            for j in (i + 2)..n_edges {
+                if i == 0 && j == n_edges - 1 {
+                    continue;
+                }
+                let crossing = edges[i].intersect(&edges[j]);
+                if let LineIntersection::OnePoint(point) = crossing {
+                    splits[i].push(point);
+                    splits[j].push(point);
+                }
+            }
+        }
+
+        let mut points = Vec::with_capacity(self.0.len());
+        for (edge, mut edge_splits) in edges.iter().zip(splits) {
+            edge_splits.retain(|&split| {
+                !coincident(split, edge.start()) && !coincident(split, edge.stop())
+            });
+            edge_splits.sort_by_key(|&split| edge.signed_distance(split));
+            points.push(edge.start());
+            points.extend(edge_splits);
+        }
+        points
+    }
+
+    // Whether the other polygon is inside this one, judging by its first point which isn't
+    // also a point of this one, for rings which may touch at their points.
+    fn encloses(&self, other: &Polygon<T>) -> bool {
+        other
+            .0
+            .iter()
+            .find(|&&point| !self.0.iter().any(|&own| coincident(own, point)))
+            .is_some_and(|&point| self.winding_number(point) != 0)
+    }
+
+    // How many times the boundary winds counterclockwise around the point, which is
+    // zero for points outside. This is negative inside polygons which wind clockwise,
+    // such as holes, and may be more than one where a polygon overlaps itself.
+    pub fn winding_number(&self, point: Point<T>) -> i32 {
+        let zero = Finite::<T>::zero();
+        let mut winding = 0;
+        for (index, &start) in self.0.iter().enumerate() {
+            let stop = self.0[(index + 1) % self.0.len()];
+            let side = (stop - start).cross(point - start);
+            if start.y <= point.y {
+                if stop.y > point.y && side > zero {
+                    winding += 1;
+                }
+            } else if stop.y <= point.y && side < zero {
+                winding -= 1;
+            }
+        }
+        winding
+    }
+
+    // Polygon made of the points at the given indices.
+    fn select(&self, indices: &[usize]) -> Polygon<T> {
+        Polygon(indices.iter().map(|&index| self.0[index]).collect())
+    }
+
+    // Cross product of the edges into and out of the middle point. Positive for a left
+    // (counterclockwise) turn.
+    fn turn(&self, prev: usize, current: usize, next: usize) -> Finite<T> {
+        let points = &self.0;
+        (points[current] - points[prev]).cross(points[next] - points[current])
+    }
+
+    // Whether a loop of point indices, in counterclockwise order, never turns clockwise.
+    fn is_convex_loop(&self, indices: &[usize]) -> bool {
+        let n = indices.len();
+        (0..n).all(|i| {
+            let turn = self.turn(indices[(i + n - 1) % n], indices[i], indices[(i + 1) % n]);
+            turn >= Finite::<T>::zero()
+        })
+    }
+
+    // Ear clipping, giving each triangle as counterclockwise indices of points. Points at
+    // which the polygon doesn't turn are left out where they would give empty triangles.
+    fn triangulate_indices(&self) -> CurvyResult<Vec<[usize; 3]>> {
+        let zero = Finite::<T>::zero();
+        let points = &self.0;
+        let mut remaining: Vec<usize> = (0..points.len()).collect();
+        if self.orientation() == Direction::Clockwise {
+            remaining.reverse();
+        }
+        let mut triangles = Vec::with_capacity(points.len() - 2);
+        while remaining.len() > 3 {
+            let n = remaining.len();
+            let ear = (0..n).find(|&i| {
+                let prev = remaining[(i + n - 1) % n];
+                let current = remaining[i];
+                let next = remaining[(i + 1) % n];
+                // An ear is a convex corner with no other points inside or on its triangle
+                self.turn(prev, current, next) > zero
+                    && !remaining.iter().any(|&other| {
+                        other != prev
+                            && other != current
+                            && other != next
+                            && in_triangle(
+                                points[other],
+                                points[prev],
+                                points[current],
+                                points[next],
+                            )
+                    })
+            });
+            let index = match ear {
+                | Some(index) => {
+                    triangles.push([
+                        remaining[(index + n - 1) % n],
+                        remaining[index],
+                        remaining[(index + 1) % n],
+                    ]);
+                    index
+                }
+                | None => {
+                    // Without ears, the only thing left to remove is a point where the
+                    // polygon goes straight.
+                    let straight = (0..n).find(|&i| {
+                        let turn = self.turn(
+                            remaining[(i + n - 1) % n],
+                            remaining[i],
+                            remaining[(i + 1) % n],
+                        );
+                        turn == zero
+                    });
+                    match straight {
+                        | Some(index) => index,
+                        | None => return curvy_err!("Polygon is not simple"),
+                    }
+                }
+            };
+            remaining.remove(index);
+        }
+        if self.turn(remaining[0], remaining[1], remaining[2]) > zero {
+            triangles.push([remaining[0], remaining[1], remaining[2]]);
+        }
+        Ok(triangles)
+    }
+}
+
+impl Polygon<f64> {
+    pub fn to_f32(&self) -> CurvyResult<Polygon<f32>> {
+        self.cast()
+    }
+}
+
+// What Polygon::offset will do with a given offset; see check_offset_orientation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OffsetHint {
+    WillGrow,
+    WillShrink,
+    Ambiguous,
+}
+
+// Where Polygon::snap snaps to. Edge i runs from point i to point i + 1.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SnapTarget<T: Value> {
+    Vertex(usize),
+    EdgeMidpoint(usize),
+    OnEdge(usize, Point<T>),
+}
+
+// A polygon with holes in it, such as from Polygon::to_rings. The outer ring winds
+// counterclockwise and the holes clockwise.
+#[derive(Clone, Debug)]
+pub struct PolygonWithHoles<T: Value> {
+    pub outer: Polygon<T>,
+    pub holes: Vec<Polygon<T>>,
 }
 
 // Generalization of polyline which includes the amount of each line to devote towards smoothing
@@ -34,6 +1310,70 @@ pub struct Polyarc<T: Value> {
     curve_sizes: Vec<Finite<T>>,
 }
 
+impl<'a, T: Value> Polyarc<T> {
+    // Normalize a path of lines, with arcs at some of the corners between them. The path
+    // must start and stop with lines, and never have two arcs in a row. Each arc must
+    // be tangent to the lines either side of it.
+    pub fn from_path(path: &Path<T>) -> CurvyResult<Self> {
+        let segments = path.segments();
+        let mut lines: Vec<Line<T>> = Vec::with_capacity(segments.len());
+        // The arc, if any, at each corner between consecutive lines.
+        let mut arcs: Vec<Option<Arc<T>>> = Vec::with_capacity(segments.len());
+        let mut after_arc: Option<Arc<T>> = None;
+        for segment in segments {
+            match segment {
+                | PathSegment::Line(line) => {
+                    if !lines.is_empty() {
+                        arcs.push(after_arc.take());
+                    }
+                    lines.push(*line);
+                }
+                | PathSegment::Arc(arc) => {
+                    if lines.is_empty() || after_arc.is_some() {
+                        return curvy_err!("Arcs must be between lines in a polyarc");
+                    }
+                    after_arc = Some(*arc);
+                }
+            }
+        }
+        if after_arc.is_some() {
+            return curvy_err!("Polyarc must stop with a line");
+        }
+
+        let mut points = Vec::with_capacity(lines.len() + 1);
+        let mut curve_sizes = Vec::with_capacity(arcs.len());
+        points.push(lines[0].start());
+        for (pair, &arc) in lines.windows(2).zip(&arcs) {
+            let (corner, curve_size) = corner_between(pair[0], pair[1], arc)?;
+            points.push(corner);
+            curve_sizes.push(curve_size);
+        }
+        points.push(lines[lines.len() - 1].stop());
+
+        Ok(Polyarc {
+            polyline: Polyline::new(points)?,
+            curve_sizes,
+        })
+    }
+
+    // The lines and arcs making up the polyarc, in order, as a path. Each arc replaces
+    // curve_size of the lines either side of its corner, and lines left with no length
+    // between two arcs are left out.
+    pub fn to_path(&self) -> Path<T> {
+        let segments = self.iter_segments().collect();
+        // Consecutive points of a polyline never coincide, so there is always a segment
+        Path::new(segments).unwrap()
+    }
+
+    pub fn polyline(&'a self) -> &'a Polyline<T> {
+        &self.polyline
+    }
+
+    pub fn curve_sizes(&'a self) -> &'a Vec<Finite<T>> {
+        &self.curve_sizes
+    }
+}
+
 // Generalization of polygon which includes the amount of each line to devote towards smoothing
 // by circular arc.
 #[derive(Clone, Debug)]
@@ -42,6 +1382,393 @@ pub struct Polycurve<T: Value> {
     curve_sizes: Vec<Finite<T>>,
 }
 
+impl<'a, T: Value> Polycurve<T> {
+    // Normalize a closed path of lines, with arcs at some of the corners between them.
+    // There must be at least three arcs, and never two arcs in a row, each tangent to
+    // the lines either side of it. The path may start at any segment, but the polygon
+    // starts at the corner before the first line.
+    pub fn from_path(path: &Path<T>) -> CurvyResult<Self> {
+        if !path.is_closed() {
+            return curvy_err!("Polycurve requires a closed path");
+        }
+        let segments = path.segments();
+        let n_segments = segments.len();
+        let first_line = segments
+            .iter()
+            .position(|segment| matches!(segment, PathSegment::Line(_)));
+        let first_line = match first_line {
+            | Some(index) => index,
+            | None => return curvy_err!("Polycurve requires lines"),
+        };
+        let mut lines: Vec<Line<T>> = Vec::with_capacity(n_segments);
+        // The arc, if any, at the corner before each line.
+        let mut arcs: Vec<Option<Arc<T>>> = Vec::with_capacity(n_segments);
+        let mut after_arc: Option<Arc<T>> = None;
+        for offset in 0..n_segments {
+            match segments[(first_line + offset) % n_segments] {
+                | PathSegment::Line(line) => {
+                    lines.push(line);
+                    arcs.push(after_arc.take());
+                }
+                | PathSegment::Arc(arc) => {
+                    if after_arc.is_some() {
+                        return curvy_err!("Arcs must be between lines in a polycurve");
+                    }
+                    after_arc = Some(arc);
+                }
+            }
+        }
+        // Wrap around to the corner before the first line
+        arcs[0] = after_arc;
+        // With no two arcs in a row, three arcs also means at least three lines.
+        if arcs.iter().filter(|arc| arc.is_some()).count() < 3 {
+            return curvy_err!("Polycurve requires at least three corner arcs");
+        }
+        let n_lines = lines.len();
+
+        let mut points = Vec::with_capacity(n_lines);
+        let mut curve_sizes = Vec::with_capacity(n_lines);
+        for (index, &line) in lines.iter().enumerate() {
+            let prev_line = lines[(index + n_lines - 1) % n_lines];
+            let (corner, curve_size) = corner_between(prev_line, line, arcs[index])?;
+            points.push(corner);
+            curve_sizes.push(curve_size);
+        }
+
+        Ok(Polycurve {
+            polygon: Polygon::new(points)?,
+            curve_sizes,
+        })
+    }
+
+    // The closed path of lines and arcs, starting after the arc at the first corner.
+    pub fn to_path(&self) -> Path<T> {
+        let segments = self.iter_segments().collect();
+        // Consecutive points of a polygon never coincide, so there is always a segment
+        Path::new(segments).unwrap()
+    }
+
+    pub fn polygon(&'a self) -> &'a Polygon<T> {
+        &self.polygon
+    }
+
+    pub fn curve_sizes(&'a self) -> &'a Vec<Finite<T>> {
+        &self.curve_sizes
+    }
+}
+
+// The arc rounding the corner between the lines from prev to corner and from corner to
+// next, which replaces curve_size of each line, along with where the arc starts and
+// stops. Corners which don't turn or aren't curved have no arc, and start and stop at the
+// corner.
+fn corner_arc<T: Value>(
+    prev: Point<T>,
+    corner: Point<T>,
+    next: Point<T>,
+    curve_size: Finite<T>,
+) -> (Point<T>, Option<Arc<T>>, Point<T>) {
+    if curve_size <= Finite::<T>::zero() {
+        return (corner, None, corner);
+    }
+    let incoming = (corner - prev).angle();
+    let outgoing = (next - corner).angle();
+    let arc_start = corner + Delta::magnitude_angle(-curve_size, incoming);
+    let arc_stop = corner + Delta::magnitude_angle(curve_size, outgoing);
+    match Arc::new(arc_start, arc_stop, incoming) {
+        | Ok(arc) => (arc_start, Some(arc), arc_stop),
+        | Err(_) => (corner, None, corner),
+    }
+}
+
+// The corner between two consecutive lines of a path, and its curve size. Without an
+// arc, the lines meet at the corner. With an arc, the corner is where the lines would
+// meet, and the arc must be tangent to both lines, replacing its curve size of each
+// either side of the corner.
+fn corner_between<T: Value>(
+    prev_line: Line<T>,
+    line: Line<T>,
+    arc: Option<Arc<T>>,
+) -> CurvyResult<(Point<T>, Finite<T>)> {
+    let arc = match arc {
+        | Some(arc) => arc,
+        | None => return Ok((line.start(), Finite::<T>::zero())),
+    };
+    let corner = match prev_line.intersect(&line) {
+        | LineIntersection::OnePoint(point) | LineIntersection::OutOfBounds(point) => point,
+        | _ => return curvy_err!("Lines around an arc never meet"),
+    };
+    // Each line touches the circle where it is nearest the center. Being tangent to
+    // lines which meet, the arc also has a control point to take its curve size from.
+    let touches = |line: Line<T>, point: Point<T>| {
+        coincident(line.apply(line.signed_distance(arc.center)), point)
+    };
+    if !touches(prev_line, arc.start()) || !touches(line, arc.stop()) {
+        return curvy_err!("Arc isn't tangent to the lines either side of it");
+    }
+    let curve_size = arc.curve_size();
+    let arc_start = prev_line.apply(prev_line.signed_distance(corner) - curve_size);
+    let arc_stop = line.apply(line.signed_distance(corner) + curve_size);
+    if !coincident(arc.start(), arc_start) || !coincident(arc.stop(), arc_stop) {
+        return curvy_err!("Arc doesn't meet the lines its curve size from the corner");
+    }
+    Ok((corner, curve_size))
+}
+
+// Whether a line clipped during offsetting has been turned around, or has no length left
+// beyond rounding error. Such lines are discarded rather than leave coincident points.
+fn vanished<T: Value>(line: Line<T>) -> bool {
+    line.length() < Finite::<T>::zero() || coincident(line.start(), line.stop())
+}
+
+// Ramer-Douglas-Peucker simplification of the chain of points from first to last,
+// adding the points it keeps after the first, which the caller has already added.
+fn douglas_peucker<T: Value>(
+    points: &[Point<T>],
+    epsilon: Finite<T>,
+    simplified: &mut Vec<Point<T>>,
+) {
+    let first = points[0];
+    let last = points[points.len() - 1];
+    // A chain which comes back to where it started is measured from that point
+    let chord = Line::new(first, last).ok();
+    let distance = |point: Point<T>| match chord {
+        | Some(chord) => chord.distance_to_point(point),
+        | None => first.distance(point),
+    };
+    let farthest = (1..(points.len() - 1))
+        .map(|index| (distance(points[index]), index))
+        .max_by_key(|&(distance, _)| distance);
+    match farthest {
+        | Some((distance, index)) if distance > epsilon => {
+            douglas_peucker(&points[..=index], epsilon, simplified);
+            douglas_peucker(&points[index..], epsilon, simplified);
+        }
+        | _ => simplified.push(last),
+    }
+}
+
+// Split a loop of points at each point it passes through more than once, into loops
+// which pass through each point only once.
+fn split_loops<T: Value>(points: Vec<Point<T>>) -> Vec<Vec<Point<T>>> {
+    let mut pending = vec![points];
+    let mut loops = Vec::new();
+    while let Some(points) = pending.pop() {
+        let repeat = (0..points.len()).find_map(|i| {
+            ((i + 1)..points.len())
+                .find(|&j| coincident(points[i], points[j]))
+                .map(|j| (i, j))
+        });
+        match repeat {
+            | Some((i, j)) => {
+                // The part between the visits is one loop, and the rest another
+                let mut rest = points[..i].to_vec();
+                rest.extend_from_slice(&points[j..]);
+                pending.push(points[i..j].to_vec());
+                pending.push(rest);
+            }
+            | None => loops.push(points),
+        }
+    }
+    loops
+}
+
+// Curve size at the corner from prev_line to line, after offsetting both lines and the
+// arc between them. The arc keeps its center, so its radius changes by the offset, and
+// its curve size by the offset times the tangent of half the turn. Sharp corners stay
+// sharp, and arcs which would shrink past nothing leave sharp corners.
+fn offset_curve_size<T: Value>(
+    prev_line: Line<T>,
+    line: Line<T>,
+    curve_size: Finite<T>,
+    offset: Finite<T>,
+) -> Finite<T> {
+    let zero = Finite::<T>::zero();
+    if curve_size <= zero {
+        return zero;
+    }
+    let two = Finite::<T>::one() + Finite::<T>::one();
+    let turn = (line.angle - prev_line.angle).radians();
+    (curve_size + offset * (turn / two).tan()).max(zero)
+}
+
+// Reduce curve sizes so that the arcs at either end of each line fit along it, keeping
+// their proportions. lengths has the length of each line in order, and curve_sizes the
+// curve size at each corner between them; when closed, the corner at index i is at the
+// start of line i, and otherwise it is between lines i and i + 1.
+fn limit_curve_sizes<T: Value>(
+    curve_sizes: &mut [Finite<T>],
+    lengths: &[Finite<T>],
+    closed: bool,
+) {
+    let n_lines = lengths.len();
+    let mut limits: Vec<Finite<T>> = curve_sizes.to_vec();
+    for (index, &length) in lengths.iter().enumerate() {
+        // The corners at the start and stop of this line, if it has them
+        let (start, stop) = if closed {
+            (Some(index), Some((index + 1) % n_lines))
+        } else {
+            (index.checked_sub(1), (index + 1 < n_lines).then_some(index))
+        };
+        let sizes = [start, stop].map(|corner| corner.map(|index| curve_sizes[index]));
+        let total = sizes
+            .iter()
+            .flatten()
+            .fold(Finite::<T>::zero(), |sum, &size| sum + size);
+        if total <= length {
+            continue;
+        }
+        for (corner, size) in [start, stop].into_iter().zip(sizes) {
+            if let (Some(corner), Some(size)) = (corner, size) {
+                limits[corner] = limits[corner].min(size * length / total);
+            }
+        }
+    }
+    curve_sizes.copy_from_slice(&limits);
+}
+
+fn points_from_flat<T: Value>(coordinates: &[[T; 2]]) -> CurvyResult<Vec<Point<T>>> {
+    coordinates
+        .iter()
+        .map(|&[x, y]| {
+            if x.is_finite() && y.is_finite() {
+                Ok(Point::new(x, y))
+            } else {
+                curvy_err!("Coordinates must be finite")
+            }
+        })
+        .collect()
+}
+
+fn points_to_flat<T: Value>(points: &[Point<T>]) -> Vec<[T; 2]> {
+    points
+        .iter()
+        .map(|point| [point.x.into_inner(), point.y.into_inner()])
+        .collect()
+}
+
+// Limit on steps of iterative searches, such as bisection.
+const MAX_ITERATIONS: usize = 200;
+
+// Whether point is inside or on the edge of the counterclockwise triangle abc.
+fn in_triangle<T: Value>(point: Point<T>, a: Point<T>, b: Point<T>, c: Point<T>) -> bool {
+    let zero = Finite::<T>::zero();
+    (b - a).cross(point - a) >= zero
+        && (c - b).cross(point - b) >= zero
+        && (a - c).cross(point - c) >= zero
+}
+
+// Join two loops of point indices, both counterclockwise, along an edge they share. Returns
+// None if they don't share an edge.
+fn merge_pieces(a: &[usize], b: &[usize]) -> Option<Vec<usize>> {
+    let (n_a, n_b) = (a.len(), b.len());
+    for k in 0..n_a {
+        let (from, to) = (a[k], a[(k + 1) % n_a]);
+        // The other loop runs along the shared edge in the opposite direction
+        let m = match (0..n_b).find(|&m| b[m] == to && b[(m + 1) % n_b] == from) {
+            | Some(m) => m,
+            | None => continue,
+        };
+        let mut merged = Vec::with_capacity(n_a + n_b - 2);
+        merged.extend((1..=n_a).map(|offset| a[(k + offset) % n_a]));
+        merged.extend((2..n_b).map(|offset| b[(m + offset) % n_b]));
+        return Some(merged);
+    }
+    None
+}
+
+// Join cells of a grid into chains of neighboring cells, breaking chains where they
+// branch. Cells sharing a side are neighbors, as are cells sharing only a corner when
+// no cell shares a side with both, so that a diagonal step isn't also taken as a turn.
+fn trace_cells(cells: &HashSet<(isize, isize)>) -> Vec<Vec<(isize, isize)>> {
+    let neighbors = |&(column, row): &(isize, isize)| -> Vec<(isize, isize)> {
+        let sides = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+        let corners = [(1, 1), (-1, 1), (-1, -1), (1, -1)];
+        let side_neighbors = sides
+            .iter()
+            .map(|(dc, dr)| (column + dc, row + dr))
+            .filter(|cell| cells.contains(cell));
+        let corner_neighbors = corners
+            .iter()
+            .filter(|(dc, dr)| {
+                !cells.contains(&(column + dc, row))
+                    && !cells.contains(&(column, row + dr))
+            })
+            .map(|(dc, dr)| (column + dc, row + dr))
+            .filter(|cell| cells.contains(cell));
+        side_neighbors.chain(corner_neighbors).collect()
+    };
+    type Cell = (isize, isize);
+    let edge = |a: Cell, b: Cell| if a < b { (a, b) } else { (b, a) };
+
+    // Sort for the same chains, in the same order, every time.
+    let mut sorted: Vec<(isize, isize)> = cells.iter().copied().collect();
+    sorted.sort();
+    let mut visited = HashSet::new();
+    let mut chains = Vec::new();
+    let mut follow = |start: Cell, next: Cell, visited: &mut HashSet<_>| {
+        let mut chain = vec![start];
+        let (mut previous, mut current) = (start, next);
+        visited.insert(edge(start, next));
+        loop {
+            chain.push(current);
+            let onward = neighbors(&current);
+            if onward.len() != 2 {
+                break;
+            }
+            let following = if onward[0] == previous { onward[1] } else { onward[0] };
+            if !visited.insert(edge(current, following)) {
+                break;
+            }
+            previous = current;
+            current = following;
+        }
+        chains.push(chain);
+    };
+    // Start from the ends and branching points of chains, then go around any loops.
+    for pass in 0..2 {
+        for &cell in sorted.iter() {
+            let cell_neighbors = neighbors(&cell);
+            if pass == 0 && cell_neighbors.len() == 2 {
+                continue;
+            }
+            for next in cell_neighbors {
+                if !visited.contains(&edge(cell, next)) {
+                    follow(cell, next, &mut visited);
+                }
+            }
+        }
+    }
+    chains
+}
+
+// Clip a convex polygon to the inside of another by the Sutherland-Hodgman algorithm. Both
+// must wind counterclockwise. The result may have repeated points, or fewer than three.
+fn clip_convex<T: Value>(subject: &[Point<T>], clip: &[Point<T>]) -> Vec<Point<T>> {
+    let zero = Finite::<T>::zero();
+    let mut output = subject.to_vec();
+    for (index, &edge_start) in clip.iter().enumerate() {
+        if output.is_empty() {
+            break;
+        }
+        let edge = clip[(index + 1) % clip.len()] - edge_start;
+        // Positive for points to the left of the edge, which are inside.
+        let side = |point: Point<T>| edge.cross(point - edge_start);
+        let input = std::mem::take(&mut output);
+        for (i, &current) in input.iter().enumerate() {
+            let previous = input[(i + input.len() - 1) % input.len()];
+            let (current_side, previous_side) = (side(current), side(previous));
+            if (current_side >= zero) != (previous_side >= zero) {
+                let t = previous_side / (previous_side - current_side);
+                output.push(previous + (current - previous) * t);
+            }
+            if current_side >= zero {
+                output.push(current);
+            }
+        }
+    }
+    output
+}
+
 pub trait Segmented<T: Value> {
     type SegmentIterator: Iterator;
     fn iter_segments(self) -> Self::SegmentIterator;
@@ -71,7 +1798,7 @@ impl<'a, T: Value> Iterator for PolylineSegmentIterator<'a, T> {
         let start_point = self.polyline.0[self.index];
         let end_point = self.polyline.0[self.index + 1];
         self.index += 1;
-        return Some(Line::new(start_point, end_point).unwrap());
+        return Some(Line::new_directed(start_point, end_point).unwrap());
     }
 }
 
@@ -98,14 +1825,110 @@ impl<'a, T: Value> Iterator for PolygonSegmentIterator<'a, T> {
             let start_point = self.polygon.0[self.index];
             let end_point = self.polygon.0[0];
             self.index += 1;
-            return Some(Line::new(start_point, end_point).unwrap());
+            return Some(Line::new_directed(start_point, end_point).unwrap());
         } else if self.index >= self.polygon.0.len() {
             return None;
         }
         let start_point = self.polygon.0[self.index];
         let end_point = self.polygon.0[self.index + 1];
         self.index += 1;
-        return Some(Line::new(start_point, end_point).unwrap());
+        return Some(Line::new_directed(start_point, end_point).unwrap());
+    }
+}
+
+// Either kind of segment along a polyarc, which is the same as for any path.
+pub type PolyarcSegment<T> = PathSegment<T>;
+
+// Either kind of segment around a polycurve, which is the same as for any path.
+pub type PolycurveSegment<T> = PathSegment<T>;
+
+// Lines and arcs along a polyarc or around a polycurve, as path segments.
+pub struct CurvedSegmentIterator<'a, T: Value> {
+    points: &'a [Point<T>],
+    // Corner i is at points[i + 1], wrapping around to points[0] if closed
+    curve_sizes: &'a [Finite<T>],
+    closed: bool,
+    // The corner whose arc, and the line before it, come next
+    index: usize,
+    line_start: Point<T>,
+    // The arc to give after the line leading up to it
+    pending_arc: Option<Arc<T>>,
+}
+
+impl<'a, T: Value> CurvedSegmentIterator<'a, T> {
+    fn new(points: &'a [Point<T>], curve_sizes: &'a [Finite<T>], closed: bool) -> Self {
+        let mut iterator = CurvedSegmentIterator {
+            points,
+            curve_sizes,
+            closed,
+            index: 0,
+            line_start: points[0],
+            pending_arc: None,
+        };
+        if closed {
+            // Start after the arc at the first point, which is then the last segment
+            let (_, _, arc_stop) = iterator.corner_arc(curve_sizes.len() - 1);
+            iterator.line_start = arc_stop;
+        }
+        iterator
+    }
+
+    // Where the arc at the given corner starts and stops, and the arc if there is one.
+    fn corner_arc(&self, index: usize) -> (Point<T>, Option<Arc<T>>, Point<T>) {
+        let n_points = self.points.len();
+        let point = |offset: usize| self.points[(index + offset) % n_points];
+        // Polycurves have a curve size for every point, polyarcs only for the corners
+        let curve_size = if self.closed {
+            self.curve_sizes[(index + 1) % n_points]
+        } else {
+            self.curve_sizes[index]
+        };
+        corner_arc(point(0), point(1), point(2), curve_size)
+    }
+}
+
+impl<'a, T: Value> Segmented<T> for &'a Polyarc<T> {
+    type SegmentIterator = CurvedSegmentIterator<'a, T>;
+    fn iter_segments(self) -> Self::SegmentIterator {
+        CurvedSegmentIterator::new(&self.polyline.0, &self.curve_sizes, false)
+    }
+}
+
+impl<'a, T: Value> Segmented<T> for &'a Polycurve<T> {
+    type SegmentIterator = CurvedSegmentIterator<'a, T>;
+    fn iter_segments(self) -> Self::SegmentIterator {
+        CurvedSegmentIterator::new(&self.polygon.0, &self.curve_sizes, true)
+    }
+}
+
+// Lines and arcs alternate, except that corners without an arc join two lines, and
+// lines used up entirely by the arcs at either end are left out. Polyarcs end with a
+// line to their last point, while polycurves end with the arc at their first point.
+impl<'a, T: Value> Iterator for CurvedSegmentIterator<'a, T> {
+    type Item = PathSegment<T>;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(arc) = self.pending_arc.take() {
+                return Some(PathSegment::Arc(arc));
+            }
+            let index = self.index;
+            let (line_stop, next_start) = if index < self.curve_sizes.len() {
+                let (arc_start, arc, arc_stop) = self.corner_arc(index);
+                self.pending_arc = arc;
+                (arc_start, arc_stop)
+            } else if index == self.curve_sizes.len() && !self.closed {
+                let last = self.points[self.points.len() - 1];
+                (last, last)
+            } else {
+                return None;
+            };
+            self.index += 1;
+            let line = Line::new_directed(self.line_start, line_stop);
+            self.line_start = next_start;
+            if let Ok(line) = line {
+                return Some(PathSegment::Line(line));
+            }
+        }
     }
 }
 
@@ -122,7 +1945,7 @@ impl<T: Value> Curved<T> for Polyline<T> {
         let n_points = self.0.len();
         // All polylines have at least two points
         assert!(n_points >= 2);
-        let two: Finite<T> = Finite::<T>::from_inner(T::from_f64(2.0).unwrap());
+        let two = T::two();
         let mut curve_sizes = Vec::<Finite<T>>::with_capacity(n_points - 2);
         let mut prev_line_length: Option<Finite<T>> = None;
         for line in self.iter_segments() {
@@ -149,7 +1972,7 @@ impl<T: Value> Curved<T> for Polygon<T> {
         let n_points = self.0.len();
         // All polygons have at least three points
         assert!(n_points >= 3);
-        let two = Finite::<T>::from_inner(T::from_f64(2.0).unwrap());
+        let two = T::two();
         let mut curve_sizes = Vec::<Finite<T>>::with_capacity(n_points);
         let mut prev_line_length: Option<Finite<T>> = None;
         let mut first_line_length: Option<Finite<T>> = None;
@@ -199,16 +2022,10 @@ impl<T: Value> Offset<T> for Polyline<T> {
                         break;
                     }
                 };
-                let intersection_point = match new_line.intersect(prev_line) {
-                    | LineIntersection::OnePoint(point)
-                    | LineIntersection::OutOfBounds(point) => point,
-                    | _ => {
-                        panic!();
-                    }
-                };
+                let intersection_point = new_line.intersect(prev_line).point().unwrap();
                 // Clip previous line based on intersection to get new connection point
                 let prev_line = prev_line.until(intersection_point);
-                if prev_line.length() < Finite::<T>::zero() {
+                if vanished(prev_line) {
                     // Discard previous line, and go back to a previous one
                     new_lines.pop();
                     continue;
@@ -226,91 +2043,186 @@ impl<T: Value> Offset<T> for Polyline<T> {
     }
 }
 
+// Offsetting inwards far enough collapses a polygon: every line but one shrinks away,
+// or the lines left over turn around. That gives an error of kind Collapsed rather
+// than a polygon.
 impl<T: Value> Offset<T> for Polygon<T> {
-    type OffsetResult = Self;
+    type OffsetResult = CurvyResult<Self>;
     fn offset(self, offset: Finite<T>) -> Self::OffsetResult {
-        let n_points = self.0.len();
-        assert!(n_points >= 3);
+        let collapsed = || {
+            curvy_err!(ErrorKind::Collapsed, "Polygon collapses at this offset")
+        };
         // Build up a temporary list of previous lines which have tentatively correct starting
         // points, but ending points subject to change.
-        let mut new_lines: Vec<Line<T>> = Vec::with_capacity(n_points);
+        let mut new_lines: Vec<Line<T>> = Vec::with_capacity(self.0.len());
         for line in self.iter_segments() {
-            let new_line = line.offset(offset);
-            loop {
-                let prev_line = match new_lines.last() {
-                    | Some(prev_line) => prev_line,
-                    | None => {
-                        new_lines.push(new_line);
-                        break;
-                    }
-                };
-                let intersection_point = match new_line.intersect(prev_line) {
-                    | LineIntersection::OnePoint(point)
-                    | LineIntersection::OutOfBounds(point) => point,
-                    | _ => {
-                        panic!();
-                    }
-                };
-                // Clip previous line based on intersection to get new connection point
-                let prev_line = prev_line.until(intersection_point);
-                if prev_line.length() < Finite::<T>::zero() {
-                    // Discard previous line, and go back to a previous one
-                    new_lines.pop();
-                    continue;
-                }
-                new_lines.push(new_line.herefrom(intersection_point));
-                break;
-            }
+            join_polygon_line(&mut new_lines, line.offset(offset), false);
         }
-        // Close ends by revisiting the first line
-        let new_line = new_lines[0].offset(offset);
-        loop {
-            let prev_line = match new_lines.last() {
-                | Some(prev_line) => prev_line,
-                | None => {
-                    new_lines.push(new_line);
-                    break;
-                }
-            };
-            let intersection_point = match new_line.intersect(prev_line) {
-                | LineIntersection::OnePoint(point)
-                | LineIntersection::OutOfBounds(point) => point,
-                | _ => {
-                    panic!();
-                }
-            };
-            // Clip previous line based on intersection to get new connection point
-            let prev_line = prev_line.until(intersection_point);
-            if prev_line.length() < Finite::<T>::zero() {
-                // Discard previous line, and go back to a previous one
-                new_lines.pop();
-                continue;
-            }
-            new_lines.push(new_line.herefrom(intersection_point));
-            break;
+        // Close ends by revisiting the first line, which is already offset
+        let first_line = new_lines[0];
+        if !join_polygon_line(&mut new_lines, first_line, true) {
+            return collapsed();
         }
         new_lines[0] = new_lines.pop().unwrap();
-
-        let mut new_points: Vec<Point<T>> = Vec::with_capacity(n_points);
-        for line in &new_lines {
-            new_points.push(line.start());
+        if new_lines.len() < 3 {
+            return collapsed();
+        }
+        // Each line must still run forwards to where the next one starts
+        let n_lines = new_lines.len();
+        for (index, line) in new_lines.iter().enumerate() {
+            if vanished(line.until(new_lines[(index + 1) % n_lines].start())) {
+                return collapsed();
+            }
         }
-        Polygon(new_points)
+        Ok(Polygon(new_lines.iter().map(|line| line.start()).collect()))
     }
 }
 
+// Each line is offset as for Polyline, and each arc keeps its center while its radius
+// grows or shrinks by the offset, as for Arc. The offset arc is still tangent to the
+// offset lines, so only its curve size changes. Arcs which shrink away entirely leave a
+// sharp corner. Where a line shrinks away, it is dropped along with the arcs at either
+// end, and the lines before and after it meet at a sharp corner instead.
 impl<T: Value> Offset<T> for Polyarc<T> {
     type OffsetResult = Self;
     fn offset(self, offset: Finite<T>) -> Self::OffsetResult {
-        // note: need to turn all convex points into actual arcs, but not concave
-        // note: need to calculate intersections between arcs and lines, probably?
-        todo!()
+        let zero = Finite::<T>::zero();
+        let lines = self.polyline.to_segments();
+        // Build up a temporary list of previous lines which have tentatively correct starting
+        // points, but ending points subject to change, each with the curve size of the
+        // corner at its start.
+        let mut new_lines: Vec<(Line<T>, Finite<T>)> = Vec::with_capacity(lines.len());
+        for (index, &line) in lines.iter().enumerate() {
+            let curve_size = match index {
+                | 0 => zero,
+                | _ => offset_curve_size(
+                    lines[index - 1],
+                    line,
+                    self.curve_sizes[index - 1],
+                    offset,
+                ),
+            };
+            join_offset_line(&mut new_lines, line.offset(offset), curve_size);
+        }
+
+        let mut points: Vec<Point<T>> =
+            new_lines.iter().map(|(line, _)| line.start()).collect();
+        points.push(new_lines.last().unwrap().0.stop());
+        let lengths: Vec<Finite<T>> =
+            new_lines.iter().map(|(line, _)| line.length()).collect();
+        let mut curve_sizes: Vec<Finite<T>> =
+            new_lines.iter().skip(1).map(|&(_, curve_size)| curve_size).collect();
+        limit_curve_sizes(&mut curve_sizes, &lengths, false);
+        Polyarc {
+            polyline: Polyline(points),
+            curve_sizes,
+        }
     }
 }
 
+// Same as for Polyarc, around the closed loop as for Polygon.
 impl<T: Value> Offset<T> for Polycurve<T> {
     type OffsetResult = Self;
     fn offset(self, offset: Finite<T>) -> Self::OffsetResult {
-        todo!()
+        let lines = self.polygon.to_segments();
+        let n_lines = lines.len();
+        // Build up a temporary list of previous lines which have tentatively correct starting
+        // points, but ending points subject to change, each with the curve size of the
+        // corner at its start.
+        let mut new_lines: Vec<(Line<T>, Finite<T>)> = Vec::with_capacity(n_lines);
+        for (index, &line) in lines.iter().enumerate() {
+            let prev_line = lines[(index + n_lines - 1) % n_lines];
+            let curve_size =
+                offset_curve_size(prev_line, line, self.curve_sizes[index], offset);
+            join_offset_line(&mut new_lines, line.offset(offset), curve_size);
+        }
+        // Close ends by revisiting the first line, which is already offset
+        let (first_line, curve_size) = new_lines[0];
+        join_offset_line(&mut new_lines, first_line, curve_size);
+        new_lines[0] = new_lines.pop().unwrap();
+
+        let points: Vec<Point<T>> =
+            new_lines.iter().map(|(line, _)| line.start()).collect();
+        let lengths: Vec<Finite<T>> =
+            new_lines.iter().map(|(line, _)| line.length()).collect();
+        let mut curve_sizes: Vec<Finite<T>> =
+            new_lines.iter().map(|&(_, curve_size)| curve_size).collect();
+        limit_curve_sizes(&mut curve_sizes, &lengths, true);
+        Polycurve {
+            polygon: Polygon(points),
+            curve_sizes,
+        }
+    }
+}
+
+// Join the next offset line of a polygon onto the last line so far, which is clipped to
+// where they meet, discarding lines which shrink away in between. Returns false if the
+// first line would be discarded while keep_first is set, as when closing the polygon.
+fn join_polygon_line<T: Value>(
+    new_lines: &mut Vec<Line<T>>,
+    new_line: Line<T>,
+    keep_first: bool,
+) -> bool {
+    loop {
+        let prev_line = match new_lines.last() {
+            | Some(&prev_line) => prev_line,
+            | None => {
+                new_lines.push(new_line);
+                return true;
+            }
+        };
+        // Lines in the same direction, from collinear points, don't cross; they meet
+        // where the previous one stops.
+        let corner = new_line
+            .intersect(&prev_line)
+            .point()
+            .unwrap_or_else(|| prev_line.stop());
+        // Clip previous line based on intersection to get new connection point
+        if vanished(prev_line.until(corner)) {
+            if keep_first && new_lines.len() == 1 {
+                return false;
+            }
+            // Discard previous line, and go back to a previous one
+            new_lines.pop();
+            continue;
+        }
+        new_lines.push(new_line.herefrom(corner));
+        return true;
+    }
+}
+
+// Add an offset line to those before it, clipping the last of them to meet it. Lines
+// which vanish when clipped are discarded, along with the arc at their start, and the
+// new line joins the one before at a sharp corner. The first line is never discarded.
+fn join_offset_line<T: Value>(
+    new_lines: &mut Vec<(Line<T>, Finite<T>)>,
+    new_line: Line<T>,
+    mut curve_size: Finite<T>,
+) {
+    loop {
+        let (prev_line, _) = match new_lines.last() {
+            | Some(last) => *last,
+            | None => {
+                new_lines.push((new_line, curve_size));
+                return;
+            }
+        };
+        // Lines in the same direction don't cross; they meet where the previous one
+        // stops.
+        let corner = new_line
+            .intersect(&prev_line)
+            .point()
+            .unwrap_or_else(|| prev_line.stop());
+        // Clip previous line based on intersection to get new connection point
+        let clipped = prev_line.until(corner);
+        if vanished(clipped) && new_lines.len() > 1 {
+            // Discard previous line, and go back to a previous one
+            new_lines.pop();
+            curve_size = Finite::<T>::zero();
+            continue;
+        }
+        new_lines.last_mut().unwrap().0 = clipped;
+        new_lines.push((new_line.herefrom(corner), curve_size));
+        return;
     }
 }
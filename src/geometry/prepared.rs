@@ -0,0 +1,76 @@
+use decorum::Finite;
+use num_traits::identities::Zero;
+
+use crate::geometry::bounds::{Bounded, BoundingBox};
+use crate::geometry::poly::Polygon;
+use crate::geometry::*;
+
+// A polygon with its bounding box and convexity worked out ahead of time, for testing
+// many points against it. Points outside the bounds are rejected straight away, and for
+// convex polygons the rest are located by binary search among the triangles fanning out
+// from the first point, rather than by following the whole boundary.
+#[derive(Clone, Debug)]
+pub struct PreparedPolygon<T: Value> {
+    // Counterclockwise, so that the fan search can rely on the direction of turns.
+    polygon: Polygon<T>,
+    bounds: BoundingBox<T>,
+    convex: bool,
+}
+
+impl<T: Value> PreparedPolygon<T> {
+    pub fn new(polygon: &Polygon<T>) -> Self {
+        let polygon = polygon.as_ccw();
+        let bounds = polygon.bounding_box();
+        let convex = polygon.is_convex();
+        PreparedPolygon {
+            polygon,
+            bounds,
+            convex,
+        }
+    }
+
+    pub fn polygon(&self) -> &Polygon<T> {
+        &self.polygon
+    }
+
+    pub fn bounds(&self) -> BoundingBox<T> {
+        self.bounds
+    }
+
+    pub fn is_convex(&self) -> bool {
+        self.convex
+    }
+
+    // Same as Polygon::contains.
+    pub fn contains(&self, point: Point<T>) -> bool {
+        if !self.bounds.contains_point(point) {
+            return false;
+        }
+        if !self.convex {
+            return self.polygon.contains(point);
+        }
+
+        let zero = Finite::<T>::zero();
+        let points = self.polygon.points();
+        let first = points[0];
+        let relative = point - first;
+        // Outside the wedge between the first and last edges
+        if (points[1] - first).cross(relative) < zero
+            || (points[points.len() - 1] - first).cross(relative) > zero
+        {
+            return false;
+        }
+        // Find the last point which the given point is to the left of, as seen from the
+        // first point; the given point is then in the triangle with the point after it.
+        let (mut low, mut high) = (1, points.len() - 1);
+        while high - low > 1 {
+            let middle = (low + high) / 2;
+            if (points[middle] - first).cross(relative) >= zero {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+        (points[high] - points[low]).cross(point - points[low]) >= zero
+    }
+}
@@ -0,0 +1,27 @@
+// Finite values are serialized as the plain values inside them. Deserializing checks that
+// the value is finite, rather than panicking as Finite::from_inner() would.
+use decorum::Finite;
+use serde::de::Error;
+use serde::{Deserializer, Serializer};
+
+use crate::geometry::*;
+
+pub fn serialize<T, S>(value: &Finite<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Value,
+    S: Serializer,
+{
+    value.into_inner().serialize(serializer)
+}
+
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Finite<T>, D::Error>
+where
+    T: Value,
+    D: Deserializer<'de>,
+{
+    let value = T::deserialize(deserializer)?;
+    if !value.is_finite() {
+        return Err(D::Error::custom(format!("{} is not finite", value)));
+    }
+    Ok(Finite::<T>::from_inner(value))
+}
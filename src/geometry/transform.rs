@@ -0,0 +1,95 @@
+use decorum::{Finite, Real};
+use num_traits::identities::{One, Zero};
+
+use crate::geometry::*;
+
+// Affine transformation of the plane, taking (x, y) to
+// (xx * x + xy * y + tx, yx * x + yy * y + ty).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Affine<T: Value> {
+    pub xx: Finite<T>,
+    pub xy: Finite<T>,
+    pub yx: Finite<T>,
+    pub yy: Finite<T>,
+    pub tx: Finite<T>,
+    pub ty: Finite<T>,
+}
+
+impl<T: Value> Affine<T> {
+    pub fn identity() -> Self {
+        Self::scale(Delta {
+            dx: Finite::<T>::one(),
+            dy: Finite::<T>::one(),
+        })
+    }
+
+    pub fn translation(delta: Delta<T>) -> Self {
+        Self {
+            tx: delta.dx,
+            ty: delta.dy,
+            ..Self::identity()
+        }
+    }
+
+    // Counterclockwise rotation about the origin.
+    pub fn rotation(angle: Angle<T>) -> Self {
+        let sin = angle.radians().sin();
+        let cos = angle.radians().cos();
+        let zero = Finite::<T>::zero();
+        Self {
+            xx: cos,
+            xy: -sin,
+            yx: sin,
+            yy: cos,
+            tx: zero,
+            ty: zero,
+        }
+    }
+
+    // Scale about the origin, by factor.dx along x and factor.dy along y.
+    pub fn scale(factor: Delta<T>) -> Self {
+        let zero = Finite::<T>::zero();
+        Self {
+            xx: factor.dx,
+            xy: zero,
+            yx: zero,
+            yy: factor.dy,
+            tx: zero,
+            ty: zero,
+        }
+    }
+
+    // The transformation which applies self, then other.
+    pub fn then(self, other: Self) -> Self {
+        Self {
+            xx: other.xx * self.xx + other.xy * self.yx,
+            xy: other.xx * self.xy + other.xy * self.yy,
+            yx: other.yx * self.xx + other.yy * self.yx,
+            yy: other.yx * self.xy + other.yy * self.yy,
+            tx: other.xx * self.tx + other.xy * self.ty + other.tx,
+            ty: other.yx * self.tx + other.yy * self.ty + other.ty,
+        }
+    }
+
+    pub fn apply(&self, point: Point<T>) -> Point<T> {
+        Point {
+            x: self.xx * point.x + self.xy * point.y + self.tx,
+            y: self.yx * point.x + self.yy * point.y + self.ty,
+        }
+    }
+
+    // Deltas are only rotated, scaled and sheared, not translated.
+    pub fn apply_delta(&self, delta: Delta<T>) -> Delta<T> {
+        Delta {
+            dx: self.xx * delta.dx + self.xy * delta.dy,
+            dy: self.yx * delta.dx + self.yy * delta.dy,
+        }
+    }
+
+    // Whether the transformation is exactly the identity, so leaves everything where it
+    // is. Shapes skip the work of transforming when it does. Near-identities, such as a
+    // rotation by a full turn, still move points far from the origin, so don't count.
+    pub fn is_identity(&self) -> bool {
+        *self == Self::identity()
+    }
+}
@@ -1,4 +1,108 @@
+use num_traits::{Signed, ToPrimitive};
+use svg::node::element::Group;
+use svg::parser::Event;
+
+use crate::geometry::poly::Polygon;
+use crate::geometry::Value;
+
+pub mod test_angle;
 pub mod test_arc;
+pub mod test_bounds;
+pub mod test_circle;
+pub mod test_delta;
 pub mod test_line;
+pub mod test_point;
 pub mod test_poly;
 pub mod test_svg;
+
+// Numbers in SVG path data are compared with this tolerance.
+const SVG_EPSILON: f64 = 1e-9;
+
+// Split SVG path data into commands and numbers. Commands may run into numbers without
+// spaces, and numbers are separated by spaces or commas.
+pub fn svg_path_tokens(d: &str) -> Vec<String> {
+    let mut spaced = String::with_capacity(2 * d.len());
+    for c in d.chars() {
+        match c {
+            | 'M' | 'm' | 'L' | 'l' | 'H' | 'h' | 'V' | 'v' | 'C' | 'c' | 'S' | 's'
+            | 'Q' | 'q' | 'T' | 't' | 'A' | 'a' | 'Z' | 'z' => {
+                spaced.push(' ');
+                spaced.push(c);
+                spaced.push(' ');
+            }
+            | ',' => spaced.push(' '),
+            | _ => spaced.push(c),
+        }
+    }
+    spaced.split_whitespace().map(String::from).collect()
+}
+
+// Assert that the first path in the group draws the expected path data, allowing for
+// rounding differences in the numbers.
+pub fn assert_svg_path_eq(group: &Group, expected_d: &str) {
+    let content = group.to_string();
+    let d = svg::read(&content)
+        .unwrap()
+        .find_map(|event| match event {
+            | Event::Tag("path", _, attributes) => {
+                attributes.get("d").map(|d| d.to_string())
+            }
+            | _ => None,
+        })
+        .expect("No path with path data in group");
+
+    let actual = svg_path_tokens(&d);
+    let expected = svg_path_tokens(expected_d);
+    assert_eq!(
+        actual.len(),
+        expected.len(),
+        "Path data {:?} doesn't match {:?}",
+        d,
+        expected_d
+    );
+    for (actual_token, expected_token) in actual.iter().zip(expected.iter()) {
+        match (actual_token.parse::<f64>(), expected_token.parse::<f64>()) {
+            | (Ok(actual_value), Ok(expected_value)) => assert!(
+                abs_diff_eq!(actual_value, expected_value, epsilon = SVG_EPSILON),
+                "Path data {:?} doesn't match {:?}",
+                d,
+                expected_d
+            ),
+            | _ => assert_eq!(
+                actual_token, expected_token,
+                "Path data {:?} doesn't match {:?}",
+                d, expected_d
+            ),
+        }
+    }
+}
+
+// Assert the area of a polygon, whichever way it winds.
+pub fn assert_area_eq<T: Value>(polygon: &Polygon<T>, expected: f64, epsilon: f64) {
+    let area = polygon.signed_area().abs().to_f64().unwrap();
+    assert!(
+        abs_diff_eq!(area, expected, epsilon = epsilon),
+        "Polygon has area {}, not {}",
+        area,
+        expected
+    );
+}
+
+// Assert the total area of some polygons, such as the pieces of a decomposition, whichever
+// way each winds.
+pub fn assert_polygons_cover_area<T: Value>(
+    polygons: &[Polygon<T>],
+    expected: f64,
+    epsilon: f64,
+) {
+    let area: f64 = polygons
+        .iter()
+        .map(|polygon| polygon.signed_area().abs().to_f64().unwrap())
+        .sum();
+    assert!(
+        abs_diff_eq!(area, expected, epsilon = epsilon),
+        "Polygons have total area {}, not {}",
+        area,
+        expected
+    );
+}
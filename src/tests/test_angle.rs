@@ -0,0 +1,92 @@
+use std::f64::consts::{PI, TAU};
+
+use decorum::Finite;
+
+use crate::geometry::*;
+
+#[test]
+fn angle_diff_scalar_arithmetic() {
+    let diff: AngleDiff<f64> = AngleDiff(Finite::from_inner(-PI / 2.0));
+    let three = Finite::from_inner(3.0);
+    assert_abs_diff_eq!((diff * three).radians().into_inner(), -1.5 * PI, epsilon = 1e-10);
+    assert_abs_diff_eq!((three * diff).radians().into_inner(), -1.5 * PI, epsilon = 1e-10);
+    assert_abs_diff_eq!((diff / three).radians().into_inner(), -PI / 6.0, epsilon = 1e-10);
+    assert_abs_diff_eq!(diff.half().radians().into_inner(), -PI / 4.0, epsilon = 1e-10);
+}
+
+#[test]
+fn angle_dms() {
+    let angle: Angle<f64> = Angle::new(1.5_f64.to_radians());
+    let (degrees, minutes, seconds) = angle.to_dms();
+    assert_eq!((degrees, minutes), (1, 30));
+    assert_eq!(seconds.into_inner(), 0.0);
+    assert_eq!(angle.format_dms(), "1°30'0\"");
+
+    let round_trip = Angle::from_dms(1, 30, Finite::from_inner(0.0));
+    assert_abs_diff_eq!(
+        round_trip.radians().into_inner(),
+        angle.radians().into_inner(),
+        epsilon = 1e-12
+    );
+
+    let angle: Angle<f64> = Angle::from_dms(123, 45, Finite::from_inner(6.5));
+    let (degrees, minutes, seconds) = angle.to_dms();
+    assert_eq!((degrees, minutes), (123, 45));
+    assert_abs_diff_eq!(seconds.into_inner(), 6.5, epsilon = 1e-6);
+
+    // Negative angles wrap around.
+    let angle: Angle<f64> = Angle::from_dms(-1, 30, Finite::from_inner(0.0));
+    let (degrees, minutes, _) = angle.to_dms();
+    assert_eq!((degrees, minutes), (358, 30));
+
+    // Angles that round up to a full turn wrap back to zero.
+    let angle: Angle<f64> = Angle::new(TAU - 1e-14);
+    let (degrees, minutes, seconds) = angle.to_dms();
+    assert_eq!((degrees, minutes, seconds.into_inner()), (0, 0, 0.0));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn angle_serde_normalized() {
+    let angle: Angle<f64> = Angle::new(1.5);
+    let json = serde_json::to_string(&angle).unwrap();
+    assert_eq!(json, "1.5");
+    let parsed: Angle<f64> = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.radians().into_inner(), 1.5);
+
+    // Angles out of range are wrapped into it, as by Angle::from
+    let parsed: Angle<f64> = serde_json::from_str("-1.0").unwrap();
+    assert_abs_diff_eq!(parsed.radians().into_inner(), TAU - 1.0, epsilon = 1e-12);
+    let parsed: Angle<f64> = serde_json::from_str("7.0").unwrap();
+    assert_abs_diff_eq!(parsed.radians().into_inner(), 7.0 - TAU, epsilon = 1e-12);
+    assert!(serde_json::from_str::<Angle<f64>>("1e400").is_err());
+}
+
+#[test]
+fn value_constants() {
+    assert_abs_diff_eq!(f64::tau().into_inner(), 2.0 * PI, epsilon = 1e-12);
+    assert_abs_diff_eq!(f64::pi().into_inner(), PI, epsilon = 1e-12);
+    assert_abs_diff_eq!(f64::frac_pi_2().into_inner(), PI / 2.0, epsilon = 1e-12);
+    assert_abs_diff_eq!(
+        f32::tau().into_inner(),
+        2.0 * std::f32::consts::PI,
+        epsilon = 1e-6
+    );
+    assert_eq!(f64::two().into_inner(), 2.0);
+    assert_eq!(f32::half().into_inner(), 0.5);
+}
+
+#[test]
+fn ordered_angle_sorts_by_radians() {
+    let mut angles: Vec<OrderedAngle<f64>> = [4.0, 0.5, 6.0, 2.0, 0.0]
+        .iter()
+        .map(|&radians| OrderedAngle(Angle::new(radians)))
+        .collect();
+    // A full turn is the same as no turn at all
+    angles.push(OrderedAngle(-Angle::new(0.0)));
+    angles.sort();
+    let radians: Vec<f64> = angles.iter().map(|a| a.0.radian_key().into_inner()).collect();
+    assert_eq!(radians, vec![0.0, 0.0, 0.5, 2.0, 4.0, 6.0]);
+    assert_eq!(OrderedAngle(Angle::new(0.0)), OrderedAngle(-Angle::new(0.0)));
+    assert!(OrderedAngle(Angle::new(0.1)) < OrderedAngle(Angle::new(6.2)));
+}
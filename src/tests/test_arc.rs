@@ -3,7 +3,9 @@ use std::f64::consts::PI;
 use decorum::Finite;
 
 use crate::geometry::*;
-use crate::geometry::arc::Arc;
+use crate::geometry::arc::{Arc, ArcIntersection, ArcIntersectionPoint};
+use crate::geometry::bounds::{Bounded, Rect};
+use crate::geometry::line::Line;
 
 
 #[test]
@@ -47,3 +49,695 @@ fn arc_negative_offset_length() {
     assert_lt!(arc.end(), arc.begin());
     assert_abs_diff_eq!(arc.length().into_inner(), -2.0_f64.sqrt() * PI / 2.0, epsilon = 1e-10)
 }
+
+#[test]
+fn arc_offset_along_normal() {
+    let center: Point<f64> = Point::new(1.0, 1.0);
+    let offset = Finite::from_inner(0.5);
+
+    // Counterclockwise arcs have normals towards their center, and grow when offset
+    let arc = Arc::from_center(center, Point::new(2.0, 1.0), Point::new(1.0, 2.0)).unwrap();
+    let normal = arc.normal_at_angle(arc.start_angle());
+    assert_eq!(normal, Angle::new(PI));
+    let moved = arc.offset(offset);
+    assert_abs_diff_eq!(moved.radius.into_inner(), 1.5, epsilon = 1e-10);
+    let expected = arc.start() + Delta::magnitude_angle(-offset, normal);
+    assert_abs_diff_eq!(moved.start(), expected, epsilon = 1e-10);
+
+    // Clockwise arcs have normals away from their center, and shrink when offset
+    let arc = Arc::from_center(center, Point::new(2.0, 1.0), Point::new(1.0, 0.0)).unwrap();
+    let normal = arc.normal_at_angle(arc.start_angle());
+    assert_eq!(normal, Angle::new(0.0));
+    let moved = arc.offset(offset);
+    assert_abs_diff_eq!(moved.radius.into_inner(), 0.5, epsilon = 1e-10);
+    let expected = arc.start() + Delta::magnitude_angle(-offset, normal);
+    assert_abs_diff_eq!(moved.start(), expected, epsilon = 1e-10);
+}
+
+#[test]
+fn arc_tangent_angles() {
+    // Clockwise
+    let start_point: Point<f64> = Point::new(1.0, 1.0);
+    let stop_point: Point<f64> = Point::new(5.0, 3.0);
+    let angle: Angle<f64> = Angle::new(PI / 4.0);
+    let arc = Arc::new(start_point, stop_point, angle).unwrap();
+    assert_abs_diff_eq!(
+        arc.tangent_angle_at_start().radians().into_inner(),
+        PI / 4.0,
+        epsilon = 1e-10
+    );
+    let stop_angle = arc.stop_angle().radians().into_inner();
+    assert_abs_diff_eq!(
+        arc.tangent_angle_at_stop().radians().into_inner(),
+        stop_angle - PI / 2.0,
+        epsilon = 1e-10
+    );
+
+    // Counterclockwise
+    let start_point: Point<f64> = Point::new(1.0, 1.0);
+    let stop_point: Point<f64> = Point::new(-1.0, 1.0);
+    let angle: Angle<f64> = Angle::new(3.0 * PI / 4.0);
+    let arc = Arc::new(start_point, stop_point, angle).unwrap();
+    assert_abs_diff_eq!(
+        arc.start_angle().radians().into_inner(),
+        PI / 4.0,
+        epsilon = 1e-10
+    );
+    assert_abs_diff_eq!(
+        arc.tangent_angle_at_start().radians().into_inner(),
+        3.0 * PI / 4.0,
+        epsilon = 1e-10
+    );
+    assert_abs_diff_eq!(
+        arc.tangent_angle_at_stop().radians().into_inner(),
+        5.0 * PI / 4.0,
+        epsilon = 1e-10
+    );
+    assert_abs_diff_eq!(arc.start(), start_point, epsilon = 1e-10);
+    assert_abs_diff_eq!(arc.stop(), stop_point, epsilon = 1e-10);
+}
+
+#[test]
+fn arc_new_large_sweep() {
+    // Heading down from (1, 0) around the unit circle to (0, 1) is three quarters of a
+    // clockwise turn.
+    let start_point: Point<f64> = Point::new(1.0, 0.0);
+    let stop_point: Point<f64> = Point::new(0.0, 1.0);
+    let angle: Angle<f64> = Angle::new(1.5 * PI);
+    let arc = Arc::new(start_point, stop_point, angle).unwrap();
+    assert_abs_diff_eq!(arc.center, Point::new(0.0, 0.0), epsilon = 1e-10);
+    assert_abs_diff_eq!(
+        arc.stop_diff.radians().into_inner(),
+        -1.5 * PI,
+        epsilon = 1e-10
+    );
+    assert_abs_diff_eq!(arc.stop(), stop_point, epsilon = 1e-10);
+}
+
+#[test]
+fn arc_from_parametric() {
+    let center: Point<f64> = Point::new(1.0, 2.0);
+    let radius = Finite::from_inner(2.0);
+    let start_angle = Angle::new(0.0);
+    let stop_angle = Angle::new(PI / 2.0);
+
+    let ccw =
+        Arc::from_parametric(center, radius, start_angle, stop_angle, Direction::Counterclockwise)
+            .unwrap();
+    assert_abs_diff_eq!(ccw.stop_diff.radians().into_inner(), PI / 2.0, epsilon = 1e-10);
+    assert_abs_diff_eq!(ccw.start(), Point::new(3.0, 2.0), epsilon = 1e-10);
+    assert_abs_diff_eq!(ccw.stop(), Point::new(1.0, 4.0), epsilon = 1e-10);
+
+    let cw = Arc::from_parametric(center, radius, start_angle, stop_angle, Direction::Clockwise)
+        .unwrap();
+    assert_abs_diff_eq!(cw.stop_diff.radians().into_inner(), -1.5 * PI, epsilon = 1e-10);
+    assert_abs_diff_eq!(cw.stop(), Point::new(1.0, 4.0), epsilon = 1e-10);
+
+    let zero = Finite::from_inner(0.0);
+    assert!(
+        Arc::from_parametric(center, zero, start_angle, stop_angle, Direction::Clockwise)
+            .is_err()
+    );
+    assert!(
+        Arc::from_parametric(center, radius, start_angle, start_angle, Direction::Clockwise)
+            .is_err()
+    );
+    assert!(
+        Arc::from_parametric(center, radius, start_angle, stop_angle, Direction::None).is_err()
+    );
+}
+
+#[test]
+fn arc_from_three_points() {
+    // Semicircle over the top of the unit circle, counterclockwise
+    let semicircle = Arc::from_three_points(
+        Point::new(1.0, 0.0),
+        Point::new(0.0, 1.0),
+        Point::new(-1.0, 0.0),
+    )
+    .unwrap();
+    assert_abs_diff_eq!(semicircle.center, Point::new(0.0, 0.0), epsilon = 1e-10);
+    assert_abs_diff_eq!(semicircle.radius.into_inner(), 1.0, epsilon = 1e-10);
+    assert_abs_diff_eq!(semicircle.stop_diff.radians().into_inner(), PI, epsilon = 1e-10);
+    assert_abs_diff_eq!(semicircle.stop(), Point::new(-1.0, 0.0), epsilon = 1e-10);
+
+    // Same ends, but through the bottom, so clockwise
+    let under = Arc::from_three_points(
+        Point::new(1.0, 0.0),
+        Point::new(0.0, -1.0),
+        Point::new(-1.0, 0.0),
+    )
+    .unwrap();
+    assert_abs_diff_eq!(under.stop_diff.radians().into_inner(), -PI, epsilon = 1e-10);
+
+    // Quarter circle about (1, 2) of radius 2, clockwise
+    let middle = Point::new(1.0 + 2.0 * (PI / 4.0).cos(), 2.0 + 2.0 * (PI / 4.0).sin());
+    let quarter =
+        Arc::from_three_points(Point::new(1.0, 4.0), middle, Point::new(3.0, 2.0)).unwrap();
+    assert_abs_diff_eq!(quarter.center, Point::new(1.0, 2.0), epsilon = 1e-10);
+    assert_abs_diff_eq!(quarter.radius.into_inner(), 2.0, epsilon = 1e-10);
+    assert_abs_diff_eq!(quarter.stop_diff.radians().into_inner(), -PI / 2.0, epsilon = 1e-10);
+    assert_abs_diff_eq!(quarter.start(), Point::new(1.0, 4.0), epsilon = 1e-10);
+    assert_abs_diff_eq!(quarter.stop(), Point::new(3.0, 2.0), epsilon = 1e-10);
+
+    // Collinear points and repeated points have no circle
+    let p1: Point<f64> = Point::new(0.0, 0.0);
+    let p2 = Point::new(1.0, 1.0);
+    let p3 = Point::new(2.0, 2.0);
+    assert!(Arc::from_three_points(p1, p2, p3).is_err());
+    assert!(Arc::from_three_points(p1, p3, p2).is_err());
+    assert!(Arc::from_three_points(p1, p1, p3).is_err());
+    assert!(Arc::from_three_points(p1, p2, p1).is_err());
+}
+
+#[test]
+fn arc_concentric() {
+    let center: Point<f64> = Point::new(1.0, 2.0);
+    let start_angle = Angle::new(0.0);
+    let stop_angle = Angle::new(PI / 2.0);
+    for direction in [Direction::Counterclockwise, Direction::Clockwise] {
+        let arc = Arc::from_parametric(
+            center,
+            Finite::from_inner(2.0),
+            start_angle,
+            stop_angle,
+            direction,
+        )
+        .unwrap();
+        let rings = arc.concentric(Finite::from_inner(1.0), 3);
+        let radii: Vec<f64> = rings.iter().map(|ring| ring.radius.into_inner()).collect();
+        assert_eq!(radii, vec![2.0, 3.0, 4.0]);
+        for ring in rings {
+            assert_eq!(ring.center, center);
+            assert_eq!(ring.start_angle, arc.start_angle);
+            assert_eq!(ring.stop_diff.radians(), arc.stop_diff.radians());
+        }
+    }
+}
+
+#[test]
+fn arc_reflect_across() {
+    let y_axis = Line::new(Point::new(0.0, -1.0), Point::new(0.0, 1.0)).unwrap();
+    // Quarter circle from (3, 1) counterclockwise to (1, 3)
+    let arc = Arc::from_parametric(
+        Point::new(1.0, 1.0),
+        Finite::from_inner(2.0),
+        Angle::new(0.0),
+        Angle::new(PI / 2.0),
+        Direction::Counterclockwise,
+    )
+    .unwrap();
+    let reflected = arc.reflect_across(&y_axis);
+    assert!(arc.sweep_flag());
+    assert!(!reflected.sweep_flag());
+    assert_abs_diff_eq!(reflected.center, Point::new(-1.0, 1.0), epsilon = 1e-10);
+    assert_abs_diff_eq!(reflected.start(), Point::new(-3.0, 1.0), epsilon = 1e-10);
+    assert_abs_diff_eq!(reflected.stop(), Point::new(-1.0, 3.0), epsilon = 1e-10);
+    assert_abs_diff_eq!(
+        reflected.length().into_inner(),
+        -arc.length().into_inner(),
+        epsilon = 1e-10
+    );
+
+    // Across a diagonal, and back again
+    let diagonal = Line::new(Point::new(0.0, 1.0), Point::new(1.0, 2.0)).unwrap();
+    let reflected = arc.reflect_across(&diagonal);
+    assert_abs_diff_eq!(reflected.start(), arc.start().reflect(&diagonal), epsilon = 1e-10);
+    assert_abs_diff_eq!(reflected.stop(), arc.stop().reflect(&diagonal), epsilon = 1e-10);
+    let back = reflected.reflect_across(&diagonal);
+    assert!(back.sweep_flag());
+    assert_abs_diff_eq!(back.start(), arc.start(), epsilon = 1e-10);
+    assert_abs_diff_eq!(back.stop(), arc.stop(), epsilon = 1e-10);
+}
+
+#[test]
+fn arc_sweep_flag() {
+    let arc = |start: f64, stop_diff: f64| Arc {
+        center: Point::new(0.0, 0.0),
+        radius: Finite::from_inner(1.0),
+        start_angle: Angle::new(start),
+        stop_diff: AngleDiff(Finite::from_inner(stop_diff)),
+    };
+    assert!(arc(0.0, PI / 2.0).sweep_flag());
+    assert!(!arc(0.0, -PI / 2.0).sweep_flag());
+    // More than half a turn, where the shorter way between the ends is the other way
+    assert!(arc(0.0, 1.5 * PI).sweep_flag());
+    assert!(!arc(PI / 2.0, -1.5 * PI).sweep_flag());
+    assert!(arc(1.5 * PI, 1.75 * PI).sweep_flag());
+}
+
+#[test]
+fn arc_chord_deviation() {
+    let center: Point<f64> = Point::new(0.0, 0.0);
+    let radius = Finite::from_inner(10.0);
+
+    // A shallow arc of 0.1 radians deviates by about r * theta^2 / 8.
+    let shallow = Arc::from_parametric(
+        center,
+        radius,
+        Angle::new(0.0),
+        Angle::new(0.1),
+        Direction::Counterclockwise,
+    )
+    .unwrap();
+    assert_abs_diff_eq!(
+        shallow.chord_deviation().into_inner(),
+        10.0 * (1.0 - 0.05_f64.cos()),
+        epsilon = 1e-10
+    );
+    assert!(shallow.is_flat(Finite::from_inner(0.05)));
+    assert!(!shallow.is_flat(Finite::from_inner(0.001)));
+
+    // A semicircle deviates by its radius, in either direction.
+    for direction in [Direction::Counterclockwise, Direction::Clockwise] {
+        let semicircle =
+            Arc::from_parametric(center, radius, Angle::new(0.0), Angle::new(PI), direction)
+                .unwrap();
+        assert_abs_diff_eq!(semicircle.chord_deviation().into_inner(), 10.0, epsilon = 1e-10);
+        assert!(!semicircle.is_flat(Finite::from_inner(1.0)));
+    }
+}
+
+#[test]
+fn arc_rotate_and_translate() {
+    let start_point: Point<f64> = Point::new(1.0, 1.0);
+    let stop_point: Point<f64> = Point::new(5.0, 3.0);
+    let arc = Arc::new(start_point, stop_point, Angle::new(PI / 4.0)).unwrap();
+
+    let translated = arc.translate(Delta::new(2.0, -1.0));
+    assert_abs_diff_eq!(translated.center, Point::new(8.0, -5.0), epsilon = 1e-10);
+    assert_abs_diff_eq!(translated.start(), Point::new(3.0, 0.0), epsilon = 1e-10);
+    assert_abs_diff_eq!(translated.stop(), Point::new(7.0, 2.0), epsilon = 1e-10);
+    assert_eq!(translated.radius, arc.radius);
+
+    let rotated = arc.rotate_about(Point::origin(), Angle::new(PI));
+    assert_abs_diff_eq!(rotated.center, Point::new(-6.0, 4.0), epsilon = 1e-10);
+    assert_abs_diff_eq!(rotated.start(), Point::new(-1.0, -1.0), epsilon = 1e-10);
+    assert_abs_diff_eq!(rotated.stop(), Point::new(-5.0, -3.0), epsilon = 1e-10);
+}
+
+#[test]
+fn arc_param_at_angle_at() {
+    let arc = Arc::from_parametric(
+        Point::new(1.0, -1.0),
+        Finite::from_inner(2.0),
+        Angle::new(PI / 4.0),
+        Angle::new(PI),
+        Direction::Counterclockwise,
+    )
+    .unwrap();
+    // As left by offsetting past the center.
+    let inverted = Arc {
+        radius: Finite::from_inner(-2.0),
+        ..arc
+    };
+
+    for arc in [arc, inverted] {
+        for i in 0..8 {
+            let t = Finite::from_inner(i as f64 * 1.5 * arc.radius.into_inner().signum());
+            assert_abs_diff_eq!(
+                arc.param_at(arc.angle_at(t)).into_inner(),
+                t.into_inner(),
+                epsilon = 1e-10
+            );
+            let point = arc.apply(t);
+            assert_abs_diff_eq!(
+                arc.signed_distance(point).into_inner(),
+                t.into_inner(),
+                epsilon = 1e-10
+            );
+        }
+        assert_abs_diff_eq!(
+            arc.apply(arc.begin()), arc.apply_angle(arc.start_angle()),
+            epsilon = 1e-10
+        );
+        assert_abs_diff_eq!(
+            arc.apply(arc.end()), arc.apply_angle(arc.stop_angle()),
+            epsilon = 1e-10
+        );
+    }
+
+    // The negative radius puts points on the opposite side of the center.
+    let root_2 = 2.0_f64.sqrt();
+    assert_abs_diff_eq!(
+        arc.start(), Point::new(1.0 + root_2, -1.0 + root_2),
+        epsilon = 1e-10
+    );
+    assert_abs_diff_eq!(
+        inverted.start(), Point::new(1.0 - root_2, -1.0 - root_2),
+        epsilon = 1e-10
+    );
+}
+
+#[test]
+fn arc_subdivide() {
+    let semicircle = Arc::from_parametric(
+        Point::new(1.0, 1.0),
+        Finite::from_inner(2.0),
+        Angle::new(PI / 2.0),
+        Angle::new(1.5 * PI),
+        Direction::Clockwise,
+    )
+    .unwrap();
+    let pieces = semicircle.subdivide(3);
+    assert_eq!(pieces.len(), 3);
+
+    let sweep: f64 = pieces.iter().map(|piece| piece.stop_diff.radians().into_inner()).sum();
+    assert_abs_diff_eq!(sweep, -PI, epsilon = 1e-10);
+    assert_abs_diff_eq!(pieces[0].start(), semicircle.start(), epsilon = 1e-10);
+    assert_abs_diff_eq!(pieces[2].stop(), semicircle.stop(), epsilon = 1e-10);
+    for pair in pieces.windows(2) {
+        assert_abs_diff_eq!(pair[0].stop(), pair[1].start(), epsilon = 1e-10);
+    }
+    for piece in pieces.iter() {
+        assert_eq!(piece.center, semicircle.center);
+        assert_eq!(piece.radius, semicircle.radius);
+    }
+    // Clockwise from the top, the middle piece crosses the positive x axis.
+    assert_abs_diff_eq!(
+        pieces[1].start_angle().radians().into_inner(),
+        PI / 6.0,
+        epsilon = 1e-10
+    );
+    assert_abs_diff_eq!(
+        pieces[1].stop_angle().radians().into_inner(),
+        11.0 * PI / 6.0,
+        epsilon = 1e-10
+    );
+
+    assert!(semicircle.subdivide(0).is_empty());
+}
+
+#[test]
+fn arc_across_seam() {
+    let arc = Arc::from_parametric(
+        Point::new(0.0, 0.0),
+        Finite::from_inner(1.0),
+        Angle::new(350.0_f64.to_radians()),
+        Angle::new(10.0_f64.to_radians()),
+        Direction::Counterclockwise,
+    )
+    .unwrap();
+    assert_gt!(arc.end(), arc.begin());
+    assert_abs_diff_eq!(
+        (arc.end() - arc.begin()).into_inner(),
+        20.0_f64.to_radians(),
+        epsilon = 1e-10
+    );
+    assert_abs_diff_eq!(
+        arc.stop(),
+        Point::new(10.0_f64.to_radians().cos(), 10.0_f64.to_radians().sin()),
+        epsilon = 1e-10
+    );
+
+    let middle = (arc.begin() + arc.end()) / Finite::from_inner(2.0);
+    let point = arc.apply_bounded(middle).unwrap();
+    assert_abs_diff_eq!(point, Point::new(1.0, 0.0), epsilon = 1e-10);
+    // The same point as given by signed_distance, within the first turn.
+    let five_degrees = 5.0_f64.to_radians();
+    let t = arc.signed_distance(Point::new(five_degrees.cos(), five_degrees.sin()));
+    assert!(arc.apply_bounded(t).is_some());
+    let outside = Finite::from_inner(20.0_f64.to_radians());
+    assert!(arc.apply_bounded(outside).is_none());
+
+    // Clockwise across the seam.
+    let arc = Arc::from_parametric(
+        Point::new(0.0, 0.0),
+        Finite::from_inner(1.0),
+        Angle::new(10.0_f64.to_radians()),
+        Angle::new(350.0_f64.to_radians()),
+        Direction::Clockwise,
+    )
+    .unwrap();
+    assert_lt!(arc.end(), arc.begin());
+    assert!(arc.apply_bounded(Finite::from_inner(0.0)).is_some());
+    assert!(arc.apply_bounded(Finite::from_inner(PI)).is_none());
+}
+
+#[test]
+fn arc_intersect_line_through_origin() {
+    let arc = Arc {
+        center: Point::new(0.0, 0.0),
+        radius: Finite::from_inner(1.0),
+        start_angle: Angle::new(0.0),
+        stop_diff: AngleDiff(Finite::from_inner(PI / 2.0)),
+    };
+    let line: Line<f64> = Line::new(Point::new(-2.0, -2.0), Point::new(2.0, 2.0)).unwrap();
+    let inside = match arc.intersect(&line) {
+        | ArcIntersection::Two(a, b) => [a, b]
+            .into_iter()
+            .filter_map(|point| match point {
+                | ArcIntersectionPoint::InBounds(point) => Some(point),
+                | _ => None,
+            })
+            .collect::<Vec<_>>(),
+        | _ => panic!("expected two intersections"),
+    };
+    let half = 0.5_f64.sqrt();
+    assert_eq!(inside.len(), 1);
+    assert_abs_diff_eq!(inside[0], Point::new(half, half), epsilon = 1e-10);
+}
+
+// Points of an arc and line intersection within the bounds of both.
+fn in_bounds(intersection: ArcIntersection<f64>) -> Vec<Point<f64>> {
+    let points = match intersection {
+        | ArcIntersection::One(a) => vec![a],
+        | ArcIntersection::Two(a, b) => vec![a, b],
+        | _ => vec![],
+    };
+    points
+        .into_iter()
+        .filter_map(|point| match point {
+            | ArcIntersectionPoint::InBounds(point) => Some(point),
+            | _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn arc_intersect_line_bounds() {
+    let upper_half = Arc {
+        center: Point::new(0.0, 0.0),
+        radius: Finite::from_inner(1.0),
+        start_angle: Angle::new(0.0),
+        stop_diff: AngleDiff(Finite::from_inner(PI)),
+    };
+    let half_root_three = 0.75_f64.sqrt();
+
+    // Only the first crossing is on the line segment, whichever solution it is
+    let line = Line::new(Point::new(-2.0, 0.5), Point::new(0.0, 0.5)).unwrap();
+    let inside = in_bounds(upper_half.intersect(&line));
+    assert_eq!(inside.len(), 1);
+    assert_abs_diff_eq!(inside[0], Point::new(-half_root_three, 0.5), epsilon = 1e-10);
+    let line = Line::new(Point::new(0.0, 0.5), Point::new(2.0, 0.5)).unwrap();
+    let inside = in_bounds(upper_half.intersect(&line));
+    assert_eq!(inside.len(), 1);
+    assert_abs_diff_eq!(inside[0], Point::new(half_root_three, 0.5), epsilon = 1e-10);
+
+    // A line segment ending on the circle includes its end point
+    let line = Line::new(Point::new(-0.5, 0.0), Point::new(1.0, 0.0)).unwrap();
+    let inside = in_bounds(upper_half.intersect(&line));
+    assert_eq!(inside.len(), 1);
+    assert_abs_diff_eq!(inside[0], Point::new(1.0, 0.0), epsilon = 1e-10);
+
+    // A clockwise arc covers the angles it turns through, not those counterclockwise
+    // from its start to its stop
+    let lower_right = Arc {
+        center: Point::new(0.0, 0.0),
+        radius: Finite::from_inner(1.0),
+        start_angle: Angle::new(0.0),
+        stop_diff: AngleDiff(Finite::from_inner(-PI / 2.0)),
+    };
+    let line = Line::new(Point::new(-2.0, -0.5), Point::new(2.0, -0.5)).unwrap();
+    let inside = in_bounds(lower_right.intersect(&line));
+    assert_eq!(inside.len(), 1);
+    assert_abs_diff_eq!(inside[0], Point::new(half_root_three, -0.5), epsilon = 1e-10);
+}
+
+#[test]
+fn arc_clip_to_rect() {
+    // Nearly a full circle, missing only the part around the bottom of the gap at 70deg
+    let arc = Arc {
+        center: Point::new(0.0, 0.0),
+        radius: Finite::from_inner(1.0),
+        start_angle: Angle::new(PI / 2.0),
+        stop_diff: AngleDiff(Finite::from_inner(340.0_f64.to_radians())),
+    };
+    let rect = Rect {
+        min: Point::new(0.5, -2.0),
+        max: Point::new(2.0, 0.8),
+    };
+
+    // Crosses the left edge on the way around, then the top edge
+    let points = arc.intersect(&rect);
+    assert_eq!(points.len(), 2);
+    assert_abs_diff_eq!(points[0], Point::new(0.5, -(0.75_f64.sqrt())), epsilon = 1e-10);
+    assert_abs_diff_eq!(points[1], Point::new(0.6, 0.8), epsilon = 1e-10);
+
+    let pieces = rect.clip_arc(arc);
+    assert_eq!(pieces.len(), 1);
+    assert_abs_diff_eq!(pieces[0].start(), points[0], epsilon = 1e-10);
+    assert_abs_diff_eq!(pieces[0].stop(), points[1], epsilon = 1e-10);
+    assert!(pieces[0].stop_diff.radians() > Finite::from_inner(0.0));
+}
+
+#[test]
+fn arc_intersection_points() {
+    let a = Point::new(1.0, 0.0);
+    let b = Point::new(0.0, 1.0);
+    let one = ArcIntersection::One(ArcIntersectionPoint::InArcBounds(a));
+    assert_eq!(one.points(), vec![a]);
+    let two = ArcIntersection::Two(
+        ArcIntersectionPoint::OutOfBounds(a),
+        ArcIntersectionPoint::InLineBounds(b),
+    );
+    assert_eq!(two.points(), vec![a, b]);
+    let two = ArcIntersection::Two(
+        ArcIntersectionPoint::InBounds(b),
+        ArcIntersectionPoint::InBounds(a),
+    );
+    assert_eq!(two.points(), vec![b, a]);
+    assert!(ArcIntersection::<f64>::None.points().is_empty());
+    assert!(ArcIntersection::<f64>::Many.points().is_empty());
+}
+
+#[test]
+fn arc_axis_extrema() {
+    let center = Point::new(1.0, -1.0);
+    let radius = Finite::from_inner(2.0);
+    let degrees = |d: f64| Angle::new(d.to_radians());
+
+    // Over the top of the circle, which is the highest point
+    let over_top = Arc::from_parametric(
+        center,
+        radius,
+        degrees(45.0),
+        degrees(135.0),
+        Direction::Counterclockwise,
+    )
+    .unwrap();
+    let extrema = over_top.axis_extrema();
+    assert_eq!(extrema.len(), 3);
+    assert_abs_diff_eq!(extrema[1], Point::new(1.0, 1.0), epsilon = 1e-10);
+    let bounds = over_top.bounding_box();
+    assert_abs_diff_eq!(bounds.max.y.into_inner(), 1.0, epsilon = 1e-10);
+    assert_abs_diff_eq!(bounds.min.y.into_inner(), 2.0_f64.sqrt() - 1.0, epsilon = 1e-10);
+
+    // Within the first quadrant the end points are the extremes
+    let first_quadrant = Arc::from_parametric(
+        center,
+        radius,
+        degrees(10.0),
+        degrees(80.0),
+        Direction::Counterclockwise,
+    )
+    .unwrap();
+    let extrema = first_quadrant.axis_extrema();
+    assert_eq!(extrema.len(), 2);
+    assert_abs_diff_eq!(extrema[0], first_quadrant.start(), epsilon = 1e-10);
+    assert_abs_diff_eq!(extrema[1], first_quadrant.stop(), epsilon = 1e-10);
+
+    // Clockwise across the seam at 0 degrees, then down past 270 degrees
+    let across_seam = Arc::from_parametric(
+        center,
+        radius,
+        degrees(30.0),
+        degrees(200.0),
+        Direction::Clockwise,
+    )
+    .unwrap();
+    let extrema = across_seam.axis_extrema();
+    assert_eq!(extrema.len(), 4);
+    assert_abs_diff_eq!(extrema[1], Point::new(3.0, -1.0), epsilon = 1e-10);
+    assert_abs_diff_eq!(extrema[2], Point::new(1.0, -3.0), epsilon = 1e-10);
+}
+
+// Counterclockwise arc between angles given in degrees.
+fn ccw_arc(center: (f64, f64), radius: f64, start: f64, stop: f64) -> Arc<f64> {
+    Arc::from_parametric(
+        Point::new(center.0, center.1),
+        Finite::from_inner(radius),
+        Angle::from(AngleDiff(Finite::from_inner(start.to_radians()))),
+        Angle::from(AngleDiff(Finite::from_inner(stop.to_radians()))),
+        Direction::Counterclockwise,
+    )
+    .unwrap()
+}
+
+#[test]
+fn arc_intersect_arc_apart() {
+    let a = ccw_arc((0.0, 0.0), 1.0, 0.0, 270.0);
+    let b = ccw_arc((5.0, 0.0), 1.0, 0.0, 270.0);
+    assert_eq!(a.intersect(&b), ArcIntersection::None);
+    // One circle inside the other
+    let c = ccw_arc((0.5, 0.0), 3.0, 0.0, 270.0);
+    assert_eq!(a.intersect(&c), ArcIntersection::None);
+}
+
+#[test]
+fn arc_intersect_arc_touching() {
+    let a = ccw_arc((0.0, 0.0), 1.0, -90.0, 90.0);
+    let b = ccw_arc((2.0, 0.0), 1.0, 90.0, 270.0);
+    match a.intersect(&b) {
+        | ArcIntersection::One(ArcIntersectionPoint::InBounds(point)) => {
+            assert_abs_diff_eq!(point, Point::new(1.0, 0.0), epsilon = 1e-10);
+        }
+        | other => panic!("expected one point in bounds, got {:?}", other),
+    }
+}
+
+#[test]
+fn arc_intersect_arc_crossing() {
+    let height = 0.75_f64.sqrt();
+    let upper = Point::new(0.5, height);
+    let lower = Point::new(0.5, -height);
+
+    let a = ccw_arc((0.0, 0.0), 1.0, -90.0, 90.0);
+    let b = ccw_arc((1.0, 0.0), 1.0, 90.0, 270.0);
+    match a.intersect(&b) {
+        | ArcIntersection::Two(
+            ArcIntersectionPoint::InBounds(first),
+            ArcIntersectionPoint::InBounds(second),
+        ) => {
+            assert_abs_diff_eq!(first, upper, epsilon = 1e-10);
+            assert_abs_diff_eq!(second, lower, epsilon = 1e-10);
+        }
+        | other => panic!("expected two points in bounds, got {:?}", other),
+    }
+
+    // Each arc reaches only one of the points
+    let a = ccw_arc((0.0, 0.0), 1.0, 0.0, 90.0);
+    let b = ccw_arc((1.0, 0.0), 1.0, 180.0, 270.0);
+    match a.intersect(&b) {
+        | ArcIntersection::Two(
+            ArcIntersectionPoint::InArcBounds(first),
+            ArcIntersectionPoint::InLineBounds(second),
+        ) => {
+            assert_abs_diff_eq!(first, upper, epsilon = 1e-10);
+            assert_abs_diff_eq!(second, lower, epsilon = 1e-10);
+        }
+        | other => panic!("expected a point within each arc, got {:?}", other),
+    }
+
+    // Only one of the points is on both arcs
+    let b = ccw_arc((1.0, 0.0), 1.0, 90.0, 180.0);
+    match a.intersect(&b) {
+        | ArcIntersection::Two(
+            ArcIntersectionPoint::InBounds(first),
+            ArcIntersectionPoint::OutOfBounds(second),
+        ) => {
+            assert_abs_diff_eq!(first, upper, epsilon = 1e-10);
+            assert_abs_diff_eq!(second, lower, epsilon = 1e-10);
+        }
+        | other => panic!("expected one point on both arcs, got {:?}", other),
+    }
+}
+
+#[test]
+fn arc_intersect_arc_concentric() {
+    let a = ccw_arc((1.0, 1.0), 2.0, 0.0, 90.0);
+    let b = ccw_arc((1.0, 1.0), 2.0, 45.0, 180.0);
+    assert_eq!(a.intersect(&b), ArcIntersection::Many);
+    let c = ccw_arc((1.0, 1.0), 3.0, 45.0, 180.0);
+    assert_eq!(a.intersect(&c), ArcIntersection::None);
+}
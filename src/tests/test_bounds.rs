@@ -0,0 +1,141 @@
+use decorum::Finite;
+
+use crate::geometry::bounds::{Bounded, BoundingBox};
+use crate::geometry::line::Line;
+use crate::geometry::poly::{Curved, Polygon, Polyline};
+use crate::geometry::*;
+
+#[test]
+fn bounding_box_from_points() {
+    let points = vec![
+        Point::new(1.0, -2.0),
+        Point::new(-3.0, 0.5),
+        Point::new(2.0, 4.0),
+        Point::new(0.0, 0.0),
+    ];
+    let bounds = BoundingBox::from_points(points).unwrap();
+    assert_eq!(bounds.min, Point::new(-3.0, -2.0));
+    assert_eq!(bounds.max, Point::new(2.0, 4.0));
+    assert_eq!(bounds.width().into_inner(), 5.0);
+    assert_eq!(bounds.height().into_inner(), 6.0);
+    assert_eq!(bounds.center(), Point::new(-0.5, 1.0));
+
+    // A single point gives an empty box around it
+    let point = Point::new(1.5, 2.5);
+    let bounds = BoundingBox::from_points([point]).unwrap();
+    assert_eq!(bounds.min, point);
+    assert_eq!(bounds.max, point);
+    assert_eq!(bounds.width().into_inner(), 0.0);
+
+    assert!(BoundingBox::<f64>::from_points(Vec::new()).is_none());
+}
+
+#[test]
+fn bounding_box_contains() {
+    let bounds = BoundingBox {
+        min: Point::new(0.0, 0.0),
+        max: Point::new(2.0, 1.0),
+    };
+    assert!(bounds.contains_point(Point::new(1.0, 0.5)));
+    // The edges and corners are inside
+    assert!(bounds.contains_point(Point::new(2.0, 0.5)));
+    assert!(bounds.contains_point(Point::new(0.0, 0.0)));
+    assert!(!bounds.contains_point(Point::new(2.5, 0.5)));
+    assert!(!bounds.contains_point(Point::new(1.0, -0.1)));
+}
+
+#[test]
+fn bounding_box_union() {
+    let a = BoundingBox {
+        min: Point::new(0.0, 0.0),
+        max: Point::new(2.0, 1.0),
+    };
+    let b = BoundingBox {
+        min: Point::new(1.0, -1.0),
+        max: Point::new(3.0, 0.5),
+    };
+    let union = a.union(&b);
+    assert_eq!(union.min, Point::new(0.0, -1.0));
+    assert_eq!(union.max, Point::new(3.0, 1.0));
+    assert_eq!(union, b.union(&a));
+    assert_eq!(a.union(&a), a);
+}
+
+#[test]
+fn bounding_box_intersects() {
+    let a = BoundingBox {
+        min: Point::new(0.0, 0.0),
+        max: Point::new(2.0, 1.0),
+    };
+    let overlapping = BoundingBox {
+        min: Point::new(1.0, 0.5),
+        max: Point::new(3.0, 3.0),
+    };
+    let touching = BoundingBox {
+        min: Point::new(2.0, 1.0),
+        max: Point::new(3.0, 3.0),
+    };
+    let apart = BoundingBox {
+        min: Point::new(2.5, 0.0),
+        max: Point::new(3.0, 1.0),
+    };
+    assert!(a.intersects(&overlapping));
+    assert!(overlapping.intersects(&a));
+    assert!(a.intersects(&touching));
+    assert!(!a.intersects(&apart));
+    assert!(!apart.intersects(&a));
+}
+
+#[test]
+fn bounding_box_to_polygon() {
+    let bounds = BoundingBox {
+        min: Point::new(1.0, 2.0),
+        max: Point::new(4.0, 4.0),
+    };
+    let polygon = bounds.to_polygon().unwrap();
+    assert_eq!(polygon.points().len(), 4);
+    assert_eq!(polygon.area().into_inner(), 6.0);
+    assert!(polygon.is_counterclockwise());
+    assert_eq!(polygon.bounding_box(), bounds);
+
+    let flat = BoundingBox {
+        min: Point::new(1.0, 2.0),
+        max: Point::new(4.0, 2.0),
+    };
+    assert!(flat.to_polygon().is_err());
+}
+
+#[test]
+fn bounded_shapes() {
+    let line = Line::new(Point::new(3.0, -1.0), Point::new(1.0, 2.0)).unwrap();
+    let bounds = line.bounding_box();
+    assert_abs_diff_eq!(bounds.min, Point::new(1.0, -1.0), epsilon = 1e-10);
+    assert_abs_diff_eq!(bounds.max, Point::new(3.0, 2.0), epsilon = 1e-10);
+
+    let points = vec![Point::new(0.0, 0.0), Point::new(4.0, 0.0), Point::new(4.0, 4.0)];
+    let polyline = Polyline::new(points).unwrap();
+    let bounds = polyline.bounding_box();
+    assert_eq!(bounds.min, Point::new(0.0, 0.0));
+    assert_eq!(bounds.max, Point::new(4.0, 4.0));
+
+    // Rounding the corners of a square leaves the edges reaching as far as before
+    let square = Polygon::from_flat(&[[0.0, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 4.0]]);
+    let bounds = square.unwrap().curve(Finite::from_inner(1.0)).bounding_box();
+    assert_abs_diff_eq!(bounds.min, Point::new(0.0, 0.0), epsilon = 1e-10);
+    assert_abs_diff_eq!(bounds.max, Point::new(4.0, 4.0), epsilon = 1e-10);
+
+    // but rounding the corners of a diamond pulls them in. Each arc has radius 1, and
+    // is centered root 2 in from its corner.
+    let diamond = Polygon::from_flat(&[[2.0, 0.0], [4.0, 2.0], [2.0, 4.0], [0.0, 2.0]]);
+    let bounds = diamond.unwrap().curve(Finite::from_inner(1.0)).bounding_box();
+    let (inset, outset) = (2.0_f64.sqrt() - 1.0, 5.0 - 2.0_f64.sqrt());
+    assert_abs_diff_eq!(bounds.min, Point::new(inset, inset), epsilon = 1e-10);
+    assert_abs_diff_eq!(bounds.max, Point::new(outset, outset), epsilon = 1e-10);
+
+    // Likewise for the open polyarc
+    let points = vec![Point::new(0.0, 2.0), Point::new(2.0, 0.0), Point::new(4.0, 2.0)];
+    let polyarc = Polyline::new(points).unwrap().curve(Finite::from_inner(1.0));
+    let bounds = polyarc.bounding_box();
+    assert_abs_diff_eq!(bounds.min, Point::new(0.0, inset), epsilon = 1e-10);
+    assert_abs_diff_eq!(bounds.max, Point::new(4.0, 2.0), epsilon = 1e-10);
+}
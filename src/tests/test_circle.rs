@@ -0,0 +1,57 @@
+use decorum::Finite;
+
+use crate::geometry::circle::Circle;
+use crate::geometry::*;
+
+#[test]
+fn circle_tangent_lines_from_outside() {
+    let circle = Circle::new(Point::new(0.0, 0.0), Finite::from_inner(1.0)).unwrap();
+    let (left, right) = circle.tangent_lines_from(Point::new(2.0, 0.0)).unwrap();
+    let half_root_3 = 3.0_f64.sqrt() / 2.0;
+
+    assert_abs_diff_eq!(left.start(), Point::new(2.0, 0.0), epsilon = 1e-10);
+    assert_abs_diff_eq!(right.start(), Point::new(2.0, 0.0), epsilon = 1e-10);
+    assert_abs_diff_eq!(left.stop(), Point::new(0.5, -half_root_3), epsilon = 1e-10);
+    assert_abs_diff_eq!(right.stop(), Point::new(0.5, half_root_3), epsilon = 1e-10);
+    assert_abs_diff_eq!(left.length().into_inner(), 3.0_f64.sqrt(), epsilon = 1e-10);
+    assert_abs_diff_eq!(right.length().into_inner(), 3.0_f64.sqrt(), epsilon = 1e-10);
+
+    // Each tangent is at a right angle to the radius where it touches
+    for tangent in [left, right] {
+        let radius = tangent.stop() - circle.center;
+        let along = tangent.stop() - tangent.start();
+        let dot = radius.dx * along.dx + radius.dy * along.dy;
+        assert_abs_diff_eq!(dot.into_inner(), 0.0, epsilon = 1e-10);
+    }
+}
+
+#[test]
+fn circle_tangent_lines_from_inside() {
+    let circle = Circle::new(Point::new(1.0, 1.0), Finite::from_inner(2.0)).unwrap();
+    assert!(circle.tangent_lines_from(Point::new(1.5, 1.0)).is_err());
+    assert!(circle.tangent_lines_from(Point::new(1.0, 1.0)).is_err());
+    // On the circle, the tangent line would have no length
+    assert!(circle.tangent_lines_from(Point::new(3.0, 1.0)).is_err());
+}
+
+#[test]
+fn circle_new_invalid() {
+    let center = Point::new(0.0, 0.0);
+    assert!(Circle::new(center, Finite::from_inner(0.0)).is_err());
+    assert!(Circle::new(center, Finite::from_inner(-1.0)).is_err());
+}
+
+#[test]
+fn circle_concentric() {
+    let center = Point::new(1.0, 2.0);
+    let one = Finite::from_inner(1.0);
+    let rings = Circle::concentric(center, one, Finite::from_inner(0.5), 4);
+    let radii: Vec<f64> = rings.iter().map(|ring| ring.radius.into_inner()).collect();
+    assert_eq!(radii, vec![1.0, 1.5, 2.0, 2.5]);
+    assert!(rings.iter().all(|ring| ring.center == center));
+
+    // Shrinking rings stop before reaching the center
+    let shrinking = Circle::concentric(center, one, Finite::from_inner(-0.5), 4);
+    assert_eq!(shrinking.len(), 2);
+    assert!(Circle::concentric(center, one, one, 0).is_empty());
+}
@@ -0,0 +1,135 @@
+use decorum::Finite;
+
+use crate::geometry::error::ErrorKind;
+use crate::geometry::line::Line;
+use crate::geometry::*;
+
+#[test]
+fn delta_try_angle_zero() {
+    let delta: Delta<f64> = Delta::new(0.0, 0.0);
+    let error = delta.try_angle().unwrap_err();
+    assert_eq!(error.kind, ErrorKind::ZeroVector);
+
+    let delta: Delta<f64> = Delta::new(-0.0, 0.0);
+    assert!(delta.try_angle().is_err());
+}
+
+#[test]
+fn delta_try_angle_nonzero() {
+    let delta: Delta<f64> = Delta::new(0.0, -2.0);
+    let angle = delta.try_angle().unwrap();
+    assert_eq!(angle, Angle::new(1.5 * std::f64::consts::PI));
+
+    // However short, a delta which isn't zero has a direction
+    let delta: Delta<f64> = Delta::new(1e-20, -1e-20);
+    let angle = delta.try_angle().unwrap();
+    assert_abs_diff_eq!(angle.radians().into_inner(), 1.75 * std::f64::consts::PI);
+}
+
+#[test]
+fn delta_try_new() {
+    assert_eq!(Delta::try_new(1.0, -2.0).unwrap(), Delta::new(1.0, -2.0));
+    let error = Delta::try_new(f64::INFINITY, 0.0).unwrap_err();
+    assert_eq!(error.kind, ErrorKind::NonFiniteValue);
+    assert!(Delta::try_new(0.0, f64::NAN).is_err());
+}
+
+#[test]
+fn line_new_nearly_coincident() {
+    // Different points, which have a direction, but too close together for it to keep
+    // them apart along the line.
+    let start_point: Point<f64> = Point::new(12.1, 5.3);
+    let end_point: Point<f64> = Point::new(12.100000000000001, 5.300000000000001);
+    assert_ne!(start_point, end_point);
+    assert!((end_point - start_point).try_angle().is_ok());
+    assert!(Line::new(start_point, end_point).is_err());
+
+    // Points as close together near the origin are still a line
+    let start_point: Point<f64> = Point::new(0.0, 0.0);
+    let end_point: Point<f64> = Point::new(1e-17, 0.0);
+    let line = Line::new(start_point, end_point).unwrap();
+    assert_eq!(line.start(), start_point);
+    assert_eq!(line.stop(), end_point);
+}
+
+#[test]
+fn delta_dot() {
+    let a: Delta<f64> = Delta::new(3.0, 4.0);
+    assert_eq!(a.dot(Delta::new(-4.0, 3.0)).into_inner(), 0.0);
+    // Parallel deltas give the product of their magnitudes, and opposite ones its negative
+    let b = Delta::new(6.0, 8.0);
+    assert_eq!(a.dot(b), a.magnitude() * b.magnitude());
+    assert_eq!(a.dot(-b), -(a.magnitude() * b.magnitude()));
+    assert_eq!(a.dot(a), a.magnitude_squared());
+}
+
+#[test]
+fn delta_cross() {
+    let east: Delta<f64> = Delta::new(2.0, 0.0);
+    // Counterclockwise from east is positive, with the area of the parallelogram
+    assert_eq!(east.cross(Delta::new(1.0, 3.0)).into_inner(), 6.0);
+    // Clockwise is negative
+    assert_eq!(east.cross(Delta::new(1.0, -3.0)).into_inner(), -6.0);
+    // Parallel or opposite is zero
+    assert_eq!(east.cross(Delta::new(5.0, 0.0)).into_inner(), 0.0);
+    assert_eq!(east.cross(Delta::new(-1.0, 0.0)).into_inner(), 0.0);
+    // Swapping the deltas changes the sign
+    let a = Delta::new(1.5, -0.5);
+    let b = Delta::new(-2.0, 4.0);
+    assert_eq!(a.cross(b), -b.cross(a));
+}
+
+#[test]
+fn delta_normalize_and_perpendicular() {
+    let delta: Delta<f64> = Delta::new(3.0, 4.0);
+    let unit = delta.normalize().unwrap();
+    assert_abs_diff_eq!(unit.dx.into_inner(), 0.6, epsilon = 1e-12);
+    assert_abs_diff_eq!(unit.dy.into_inner(), 0.8, epsilon = 1e-12);
+    assert_eq!(Delta::<f64>::new(0.0, 0.0).normalize(), None);
+    let unit = Delta::<f64>::new(3e-20, 4e-20).normalize().unwrap();
+    assert_abs_diff_eq!(unit.dx.into_inner(), 0.6, epsilon = 1e-12);
+    assert_abs_diff_eq!(unit.dy.into_inner(), 0.8, epsilon = 1e-12);
+    assert_eq!(Delta::<f64>::new(1e-200, 0.0).normalize(), None);
+
+    // A quarter turn counterclockwise, so orthogonal and to the left
+    let perpendicular = delta.perpendicular();
+    assert_abs_diff_eq!(perpendicular.dx.into_inner(), -0.8, epsilon = 1e-12);
+    assert_abs_diff_eq!(perpendicular.dy.into_inner(), 0.6, epsilon = 1e-12);
+    assert_abs_diff_eq!(delta.dot(perpendicular).into_inner(), 0.0, epsilon = 1e-12);
+    assert!(delta.cross(perpendicular).into_inner() > 0.0);
+    assert_eq!(Delta::<f64>::new(0.0, 0.0).perpendicular(), Delta::new(0.0, 0.0));
+}
+
+#[test]
+fn delta_project_onto() {
+    let delta: Delta<f64> = Delta::new(3.0, 4.0);
+    // Orthogonal deltas have nothing along each other
+    let orthogonal = delta.project_onto(Delta::new(-8.0, 6.0));
+    assert_abs_diff_eq!(orthogonal.magnitude().into_inner(), 0.0, epsilon = 1e-12);
+    // Parallel and anti-parallel deltas project to the whole delta, whatever the
+    // length or direction of the other
+    for other in [Delta::new(0.3, 0.4), Delta::new(-6.0, -8.0)] {
+        let parallel = delta.project_onto(other);
+        assert_abs_diff_eq!(parallel.dx.into_inner(), 3.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(parallel.dy.into_inner(), 4.0, epsilon = 1e-12);
+    }
+    // Otherwise, the part along the other
+    let along_x = delta.project_onto(Delta::new(-2.0, 0.0));
+    assert_abs_diff_eq!(along_x.dx.into_inner(), 3.0, epsilon = 1e-12);
+    assert_abs_diff_eq!(along_x.dy.into_inner(), 0.0, epsilon = 1e-12);
+    assert_eq!(delta.project_onto(Delta::new(0.0, 0.0)), Delta::new(0.0, 0.0));
+}
+
+#[test]
+fn delta_with_magnitude() {
+    let delta: Delta<f64> = Delta::new(3.0, 4.0);
+    let longer = delta.with_magnitude(Finite::from_inner(10.0)).unwrap();
+    assert_abs_diff_eq!(longer.dx.into_inner(), 6.0, epsilon = 1e-12);
+    assert_abs_diff_eq!(longer.dy.into_inner(), 8.0, epsilon = 1e-12);
+    // A negative magnitude turns it around
+    let reversed = delta.with_magnitude(Finite::from_inner(-1.0)).unwrap();
+    assert_abs_diff_eq!(reversed.dx.into_inner(), -0.6, epsilon = 1e-12);
+    assert_abs_diff_eq!(reversed.dy.into_inner(), -0.8, epsilon = 1e-12);
+    let zero = Delta::<f64>::new(0.0, 0.0);
+    assert_eq!(zero.with_magnitude(Finite::from_inner(1.0)), None);
+}
@@ -3,6 +3,7 @@ use std::f64::consts::PI;
 use decorum::Finite;
 
 use crate::geometry::line::{Line, LineIntersection};
+use crate::geometry::line_set::LineSet;
 use crate::geometry::*;
 
 #[test]
@@ -44,3 +45,231 @@ fn line_intersection() {
         _ => unreachable!()
     }
 }
+
+#[test]
+fn line_signed_offset() {
+    let start_point: Point<f64> = Point::new(0.0, 1.0);
+    let end_point: Point<f64> = Point::new(2.0, 1.0);
+    let line = Line::new(start_point, end_point).unwrap();
+
+    // A positive offset moves a rightward line towards -90deg: down with the y axis
+    // pointing up, or up on screen with the y axis pointing down.
+    let moved = line.signed_offset(Finite::from_inner(0.5));
+    assert_abs_diff_eq!(moved.start(), Point::new(0.0, 0.5), epsilon = 1e-10);
+    assert_abs_diff_eq!(moved.stop(), Point::new(2.0, 0.5), epsilon = 1e-10);
+
+    let moved = line.signed_offset(Finite::from_inner(-0.5));
+    assert_abs_diff_eq!(moved.start(), Point::new(0.0, 1.5), epsilon = 1e-10);
+    assert_abs_diff_eq!(moved.stop(), Point::new(2.0, 1.5), epsilon = 1e-10);
+
+    // Offset follows the same convention
+    let moved = line.offset(Finite::from_inner(0.5));
+    assert_abs_diff_eq!(moved.start(), Point::new(0.0, 0.5), epsilon = 1e-10);
+}
+
+#[test]
+fn line_offset_along_normal() {
+    let start_point: Point<f64> = Point::new(2.0, 4.0);
+    let end_point: Point<f64> = Point::new(4.0, -2.0);
+    let line = Line::new(start_point, end_point).unwrap();
+    let moved = line.offset(Finite::from_inner(1.5));
+    // A positive offset moves the line opposite to its normal
+    let expected = start_point + Delta::magnitude_angle(Finite::from_inner(-1.5), line.normal());
+    assert_abs_diff_eq!(moved.start(), expected, epsilon = 1e-10);
+}
+
+#[test]
+fn line_intersection_through_origin() {
+    let line1: Line<f64> = Line::new(Point::new(-1.0, 0.0), Point::new(1.0, 0.0)).unwrap();
+    let line2: Line<f64> = Line::new(Point::new(0.5, -1.0), Point::new(0.5, 1.0)).unwrap();
+    match line1.intersect(&line2) {
+        LineIntersection::OnePoint(point) => {
+            assert_abs_diff_eq!(point, Point::new(0.5, 0.0), epsilon = 1e-10)
+        }
+        _ => unreachable!(),
+    }
+
+    // The other line through the origin
+    match line2.intersect(&line1) {
+        LineIntersection::OnePoint(point) => {
+            assert_abs_diff_eq!(point, Point::new(0.5, 0.0), epsilon = 1e-10)
+        }
+        _ => unreachable!(),
+    }
+
+    // Both lines through the origin, crossing there
+    let line3: Line<f64> = Line::new(Point::new(-1.0, -1.0), Point::new(1.0, 1.0)).unwrap();
+    match line1.intersect(&line3) {
+        LineIntersection::OnePoint(point) => {
+            assert_abs_diff_eq!(point, Point::new(0.0, 0.0), epsilon = 1e-10)
+        }
+        _ => unreachable!(),
+    }
+
+    // Lines whose nearest points to the origin are on either side of it, crossing out
+    // of bounds of the first
+    let line4: Line<f64> = Line::new(Point::new(-2.0, -1.0), Point::new(-2.0, 1.0)).unwrap();
+    let line5: Line<f64> = Line::new(Point::new(3.0, 1.0), Point::new(-3.0, -2.0)).unwrap();
+    match line2.intersect(&line5) {
+        LineIntersection::OnePoint(point) => {
+            assert_abs_diff_eq!(point, Point::new(0.5, -0.25), epsilon = 1e-10)
+        }
+        _ => unreachable!(),
+    }
+    match line4.intersect(&line5) {
+        LineIntersection::OutOfBounds(point) => {
+            assert_abs_diff_eq!(point, Point::new(-2.0, -1.5), epsilon = 1e-10)
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn line_rasterize_horizontal() {
+    let line = Line::new(Point::new(0.5, 2.5), Point::new(4.5, 2.5)).unwrap();
+    let cells = line.rasterize(Finite::from_inner(1.0)).unwrap();
+    assert_eq!(cells, vec![(0, 2), (1, 2), (2, 2), (3, 2), (4, 2)]);
+
+    // Cell size scales the grid, and negative coordinates round down.
+    let line = Line::new(Point::new(3.0, -1.0), Point::new(-3.0, -1.0)).unwrap();
+    let cells = line.rasterize(Finite::from_inner(2.0)).unwrap();
+    assert_eq!(cells, vec![(1, -1), (0, -1), (-1, -1), (-2, -1)]);
+}
+
+#[test]
+fn line_rasterize_diagonal() {
+    let line = Line::new(Point::new(0.5, 0.5), Point::new(3.5, 3.5)).unwrap();
+    let cells = line.rasterize(Finite::from_inner(1.0)).unwrap();
+    assert_eq!(cells, vec![(0, 0), (1, 1), (2, 2), (3, 3)]);
+
+    let line = Line::new(Point::new(0.5, 3.5), Point::new(3.5, 0.5)).unwrap();
+    let cells = line.rasterize(Finite::from_inner(1.0)).unwrap();
+    assert_eq!(cells, vec![(0, 3), (1, 2), (2, 1), (3, 0)]);
+}
+
+#[test]
+fn line_rasterize_invalid_cell_size() {
+    let line = Line::new(Point::new(0.5, 0.5), Point::new(3.5, 3.5)).unwrap();
+    assert!(line.rasterize(Finite::from_inner(0.0)).is_err());
+    assert!(line.rasterize(Finite::from_inner(-1.0)).is_err());
+    // Too many cells to index
+    assert!(line.rasterize(Finite::from_inner(1e-300)).is_err());
+    let far = Line::new(Point::new(-1e30, 0.0), Point::new(1e30, 1.0)).unwrap();
+    assert!(far.rasterize(Finite::from_inner(1e-2)).is_err());
+    // Each end's index fits, but not the number of cells between them
+    let wide = Line::new(Point::new(-9e18, 0.0), Point::new(9e18, 1.0)).unwrap();
+    assert!(wide.rasterize(Finite::from_inner(1.0)).is_err());
+}
+
+#[test]
+fn line_parametric_form() {
+    let start_point: Point<f64> = Point::new(2.0, 4.0);
+    let end_point: Point<f64> = Point::new(4.0, -2.0);
+    let line = Line::new(start_point, end_point).unwrap();
+
+    let (base, direction) = line.parametric_form();
+    assert_abs_diff_eq!(base, Point::new(3.0, 1.0), epsilon = 1e-10);
+    assert_abs_diff_eq!(direction.magnitude().into_inner(), 1.0, epsilon = 1e-10);
+    assert_abs_diff_eq!(
+        base + direction * line.begin(),
+        start_point,
+        epsilon = 1e-10
+    );
+    assert_abs_diff_eq!(base + direction * line.end(), end_point, epsilon = 1e-10);
+}
+
+#[test]
+fn line_rotate_and_translate() {
+    let line = Line::new(Point::new(1.0, 2.0), Point::new(4.0, 2.0)).unwrap();
+
+    let rotated = line.rotate_about(Point::origin(), Angle::new(PI / 2.0));
+    assert_abs_diff_eq!(rotated.start(), Point::new(-2.0, 1.0), epsilon = 1e-10);
+    assert_abs_diff_eq!(rotated.stop(), Point::new(-2.0, 4.0), epsilon = 1e-10);
+    assert_abs_diff_eq!(rotated.angle.radians().into_inner(), PI / 2.0, epsilon = 1e-10);
+
+    let rotated = line.rotate_about(Point::new(1.0, 1.0), Angle::new(PI / 2.0));
+    assert_abs_diff_eq!(rotated.start(), Point::new(0.0, 1.0), epsilon = 1e-10);
+    assert_abs_diff_eq!(rotated.stop(), Point::new(0.0, 4.0), epsilon = 1e-10);
+
+    let translated = line.translate(Delta::new(-1.0, 3.0));
+    assert_abs_diff_eq!(translated.start(), Point::new(0.0, 5.0), epsilon = 1e-10);
+    assert_abs_diff_eq!(translated.stop(), Point::new(3.0, 5.0), epsilon = 1e-10);
+}
+
+#[test]
+fn line_set_matches_brute_force() {
+    // A grid of short diagonal segments, plus some long ones which span the grid.
+    let mut lines = Vec::new();
+    for i in 0..20 {
+        for j in 0..20 {
+            let (x, y) = (i as f64 * 5.0, j as f64 * 5.0);
+            lines.push(Line::new(Point::new(x, y), Point::new(x + 4.0, y + 3.0)).unwrap());
+            lines.push(Line::new(Point::new(x + 4.0, y), Point::new(x, y + 3.0)).unwrap());
+        }
+    }
+    lines.push(Line::new(Point::new(-10.0, 50.0), Point::new(110.0, 52.0)).unwrap());
+    lines.push(Line::new(Point::new(30.0, -10.0), Point::new(32.0, 110.0)).unwrap());
+    let line_set = LineSet::new(lines.clone());
+    assert_eq!(line_set.len(), lines.len());
+
+    let queries = [
+        Line::new(Point::new(0.0, 0.0), Point::new(100.0, 100.0)).unwrap(),
+        Line::new(Point::new(21.0, 60.0), Point::new(27.0, 41.0)).unwrap(),
+        Line::new(Point::new(-5.0, 2.0), Point::new(120.0, 2.0)).unwrap(),
+        Line::new(Point::new(200.0, 200.0), Point::new(210.0, 220.0)).unwrap(),
+    ];
+    for query in queries.iter() {
+        let expected: Vec<Point<f64>> = lines
+            .iter()
+            .filter_map(|line| match query.intersect(line) {
+                | LineIntersection::OnePoint(point) => Some(point),
+                | _ => None,
+            })
+            .collect();
+        assert_eq!(line_set.intersect_line(query), expected);
+    }
+    assert!(line_set.intersect_line(&queries[3]).is_empty());
+    assert!(!line_set.intersect_line(&queries[0]).is_empty());
+}
+
+#[test]
+fn line_intersection_point() {
+    let point = Point::new(1.0, 2.0);
+    assert_eq!(LineIntersection::OnePoint(point).point(), Some(point));
+    assert_eq!(LineIntersection::OutOfBounds(point).point(), Some(point));
+    assert_eq!(LineIntersection::<f64>::None.point(), None);
+    assert_eq!(LineIntersection::<f64>::Many.point(), None);
+    assert_eq!(LineIntersection::<f64>::ManyOutOfBounds.point(), None);
+
+    let a: Line<f64> = Line::new(Point::new(0.0, 0.0), Point::new(1.0, 1.0)).unwrap();
+    let b: Line<f64> = Line::new(Point::new(3.0, 0.0), Point::new(2.0, 1.0)).unwrap();
+    assert_abs_diff_eq!(a.intersect(&b).point().unwrap(), Point::new(1.5, 1.5), epsilon = 1e-10);
+}
+
+#[test]
+fn line_distance_to_point() {
+    let line = Line::new(Point::new(0.0, 0.0), Point::new(2.0, 0.0)).unwrap();
+    let distance = |x: f64, y: f64| line.distance_to_point(Point::new(x, y)).into_inner();
+    assert_abs_diff_eq!(distance(1.0, 3.0), 3.0, epsilon = 1e-10);
+    assert_abs_diff_eq!(distance(1.0, 0.0), 0.0, epsilon = 1e-10);
+    // Beyond the ends, the distance is to the nearer end
+    assert_abs_diff_eq!(distance(5.0, 4.0), 5.0, epsilon = 1e-10);
+    assert_abs_diff_eq!(distance(-1.0, 0.0), 1.0, epsilon = 1e-10);
+}
+
+#[test]
+fn line_reflect_across() {
+    let y_axis = Line::new(Point::new(0.0, -1.0), Point::new(0.0, 1.0)).unwrap();
+    let line = Line::new(Point::new(1.0, 2.0), Point::new(3.0, -1.0)).unwrap();
+    let reflected = line.reflect_across(&y_axis);
+    assert_abs_diff_eq!(reflected.start(), Point::new(-1.0, 2.0), epsilon = 1e-10);
+    assert_abs_diff_eq!(reflected.stop(), Point::new(-3.0, -1.0), epsilon = 1e-10);
+    assert_abs_diff_eq!(
+        reflected.length().into_inner(),
+        line.length().into_inner(),
+        epsilon = 1e-10
+    );
+    let back = reflected.reflect_across(&y_axis);
+    assert_abs_diff_eq!(back.start(), line.start(), epsilon = 1e-10);
+    assert_abs_diff_eq!(back.stop(), line.stop(), epsilon = 1e-10);
+}
@@ -0,0 +1,299 @@
+use std::collections::HashMap;
+use std::f64::consts::PI;
+
+use decorum::Finite;
+
+use crate::geometry::error::ErrorKind;
+use crate::geometry::line::Line;
+use crate::geometry::transform::Affine;
+use crate::geometry::*;
+
+#[test]
+fn sunflower_fills_circle() {
+    let center: Point<f64> = Point::new(3.0, -2.0);
+    let radius = Finite::from_inner(5.0);
+    let points = sunflower(center, radius, 200);
+    assert_eq!(points.len(), 200);
+    for point in points.iter() {
+        assert_lt!(point.distance(center), radius);
+    }
+
+    // Each quadrant gets about a quarter of the points.
+    let upper_right = points
+        .iter()
+        .filter(|point| point.x > center.x && point.y > center.y)
+        .count();
+    assert_gt!(upper_right, 40);
+    assert_lt!(upper_right, 60);
+
+    assert!(sunflower(center, radius, 0).is_empty());
+}
+
+#[test]
+fn batch_rotate_matches_rotate() {
+    let angle = Angle::new(1.2);
+    let deltas: Vec<Delta<f64>> =
+        (0..50).map(|i| Delta::new(i as f64 * 0.7 - 10.0, 3.0 - i as f64)).collect();
+    let mut rotated = deltas.clone();
+    Delta::rotate_all(&mut rotated, angle);
+    for (delta, batch) in deltas.iter().zip(rotated.iter()) {
+        assert_eq!(delta.rotate(angle), *batch);
+    }
+
+    let center = Point::new(2.0, -1.0);
+    let transformation = Affine::translation(Point::origin() - center)
+        .then(Affine::rotation(angle))
+        .then(Affine::translation(center - Point::origin()));
+    let points: Vec<Point<f64>> = deltas.iter().map(|&delta| Point::origin() + delta).collect();
+    let mut transformed = points.clone();
+    Point::transform_all(&mut transformed, &transformation);
+    for (point, batch) in points.iter().zip(transformed.iter()) {
+        assert_abs_diff_eq!(point.rotate_about(center, angle), *batch, epsilon = 1e-10);
+        assert_eq!(transformation.apply(*point), *batch);
+    }
+}
+
+#[test]
+fn point_lerp() {
+    let a = Point::new(1.0, -2.0);
+    let b = Point::new(3.0, 4.0);
+    let lerp = |t: f64| a.lerp(b, Finite::from_inner(t));
+    assert_eq!(lerp(0.0), a);
+    assert_eq!(lerp(1.0), b);
+    assert_abs_diff_eq!(lerp(0.5), a.midpoint(b), epsilon = 1e-12);
+    assert_abs_diff_eq!(lerp(2.0), Point::new(5.0, 10.0), epsilon = 1e-12);
+    assert_abs_diff_eq!(lerp(-1.0), Point::new(-1.0, -8.0), epsilon = 1e-12);
+}
+
+#[test]
+fn point_scale_about() {
+    let pivot = Point::new(1.0, 2.0);
+    let point = Point::new(3.0, 1.0);
+    assert_eq!(point.scale_about(pivot, Delta::new(2.0, 3.0)), Point::new(5.0, -1.0));
+    assert_eq!(point.scale_about(pivot, Delta::new(1.0, 1.0)), point);
+    // Zero collapses onto the pivot, along one axis or both
+    assert_eq!(point.scale_about(pivot, Delta::new(0.0, 0.0)), pivot);
+    assert_eq!(point.scale_about(pivot, Delta::new(0.0, 1.0)), Point::new(1.0, 1.0));
+    // Negative factors reflect across the pivot
+    assert_eq!(point.scale_about(pivot, Delta::new(-1.0, -1.0)), Point::new(-1.0, 3.0));
+    assert_eq!(point.scale_about(pivot, Delta::new(-1.0, 1.0)), Point::new(-1.0, 1.0));
+
+    let angle = Angle::new(0.7);
+    let uniform = Delta::new(1.5, 1.5);
+    assert_abs_diff_eq!(
+        point.scale_about(pivot, uniform).rotate_about(pivot, angle),
+        point.rotate_about(pivot, angle).scale_about(pivot, uniform),
+        epsilon = 1e-10
+    );
+}
+
+#[test]
+fn point_centroid() {
+    let point = Point::new(2.5, -1.0);
+    assert_eq!(Point::centroid([point]), Some(point));
+
+    let square = vec![
+        Point::new(1.0, 1.0),
+        Point::new(3.0, 1.0),
+        Point::new(3.0, 3.0),
+        Point::new(1.0, 3.0),
+    ];
+    assert_eq!(Point::centroid(square), Some(Point::new(2.0, 2.0)));
+
+    assert_eq!(Point::<f64>::centroid(Vec::new()), None);
+}
+
+#[test]
+fn point_from_tuple_and_array() {
+    assert_eq!(Point::from((1.0, 2.0)), Point::new(1.0, 2.0));
+    assert_eq!(Point::from([1.0, 2.0]), Point::new(1.0, 2.0));
+
+    let point = Point::new(-3.5, 0.25);
+    let tuple: (f64, f64) = point.into();
+    let array: [f64; 2] = point.into();
+    assert_eq!(tuple, (-3.5, 0.25));
+    assert_eq!(array, [-3.5, 0.25]);
+    assert_eq!(Point::from(tuple), point);
+    assert_eq!(Point::from(array), point);
+}
+
+#[test]
+#[should_panic]
+fn point_from_non_finite_tuple() {
+    let _ = Point::from((f64::NAN, 0.0));
+}
+
+#[test]
+fn point_try_new() {
+    assert_eq!(Point::try_new(1.0, -2.0).unwrap(), Point::new(1.0, -2.0));
+    let error = Point::try_new(f64::INFINITY, 0.0).unwrap_err();
+    assert_eq!(error.kind, ErrorKind::NonFiniteValue);
+    assert!(Point::try_new(0.0, f64::NEG_INFINITY).is_err());
+    assert!(Point::try_new(f64::NAN, 0.0).is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn point_serde_round_trip() {
+    let point = Point::new(1.5, -2.0);
+    let json = serde_json::to_string(&point).unwrap();
+    assert_eq!(json, r#"{"x":1.5,"y":-2.0}"#);
+    let parsed: Point<f64> = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, point);
+}
+
+#[test]
+fn point_hash_map_key() {
+    let mut indices = HashMap::new();
+    indices.insert(Point::new(1.0, 2.0), 0);
+    indices.insert(Point::new(1.0, 2.0), 1);
+    assert_eq!(indices.len(), 1);
+    assert_eq!(indices[&Point::new(1.0, 2.0)], 1);
+
+    // Zero and negative zero are equal, so must be the same key
+    indices.insert(Point::new(0.0, 0.0), 2);
+    indices.insert(Point::new(-0.0, 0.0), 3);
+    assert_eq!(Point::new(0.0, 0.0), Point::new(-0.0, 0.0));
+    assert_eq!(indices.len(), 2);
+}
+
+#[test]
+fn point_manhattan_distance() {
+    let origin = Point::new(0.0, 0.0);
+    assert_eq!(origin.manhattan_distance(Point::new(3.0, 4.0)).into_inner(), 7.0);
+    assert_eq!(Point::new(3.0, 4.0).manhattan_distance(origin).into_inner(), 7.0);
+    assert_eq!(origin.manhattan_distance(Point::new(-2.0, 0.5)).into_inner(), 2.5);
+}
+
+#[test]
+fn point_reflect() {
+    let x_axis = Line::new(Point::new(-1.0, 0.0), Point::new(1.0, 0.0)).unwrap();
+    let reflected = Point::new(3.0, 2.0).reflect(&x_axis);
+    assert_abs_diff_eq!(reflected, Point::new(3.0, -2.0), epsilon = 1e-10);
+
+    // Reflecting across a 45 degree line swaps x and y, even beyond the line's ends
+    let diagonal = Line::new(Point::new(0.0, 0.0), Point::new(1.0, 1.0)).unwrap();
+    let reflected = Point::new(5.0, -1.0).reflect(&diagonal);
+    assert_abs_diff_eq!(reflected, Point::new(-1.0, 5.0), epsilon = 1e-10);
+
+    // A line away from the origin
+    let line = Line::new(Point::new(0.0, 2.0), Point::new(2.0, 4.0)).unwrap();
+    let reflected = Point::new(1.0, 0.0).reflect(&line);
+    assert_abs_diff_eq!(reflected, Point::new(-2.0, 3.0), epsilon = 1e-10);
+    let back = reflected.reflect(&line);
+    assert_abs_diff_eq!(back, Point::new(1.0, 0.0), epsilon = 1e-10);
+
+    let on_line = Point::new(1.0, 3.0);
+    assert_abs_diff_eq!(on_line.reflect(&line), on_line, epsilon = 1e-10);
+}
+
+#[test]
+fn point_distance_squared() {
+    let a = Point::new(1.0, 2.0);
+    let b = Point::new(4.0, 6.0);
+    assert_eq!(a.distance_squared(b).into_inner(), 25.0);
+    assert_eq!((b - a).magnitude_squared().into_inner(), 25.0);
+
+    let c = Point::new(-0.3, 7.1);
+    let distance = a.distance(c).into_inner();
+    let squared = a.distance_squared(c).into_inner();
+    assert_abs_diff_eq!(squared, distance * distance, epsilon = 1e-10);
+}
+
+#[test]
+fn point_sort_by_x_then_y() {
+    let mut points = vec![
+        Point::new(1.0, 2.0),
+        Point::new(-1.0, 5.0),
+        Point::new(1.0, -3.0),
+        Point::new(0.0, 0.0),
+        Point::new(-1.0, 4.0),
+    ];
+    points.sort();
+    let expected = vec![
+        Point::new(-1.0, 4.0),
+        Point::new(-1.0, 5.0),
+        Point::new(0.0, 0.0),
+        Point::new(1.0, -3.0),
+        Point::new(1.0, 2.0),
+    ];
+    assert_eq!(points, expected);
+    assert!(Point::new(0.0, 1.0) < Point::new(0.0, 2.0));
+    assert_eq!(Point::new(0.0, 1.0).max(Point::new(-2.0, 9.0)), Point::new(0.0, 1.0));
+}
+
+#[test]
+fn point_clamp() {
+    let (min, max) = (Point::new(0.0, 0.0), Point::new(4.0, 2.0));
+    let inside = Point::new(1.0, 1.5);
+    assert_eq!(inside.clamp(min, max), inside);
+    assert_eq!(Point::new(5.0, 1.0).clamp(min, max), Point::new(4.0, 1.0));
+    assert_eq!(Point::new(-1.0, 1.0).clamp(min, max), Point::new(0.0, 1.0));
+    assert_eq!(Point::new(2.0, 3.0).clamp(min, max), Point::new(2.0, 2.0));
+    assert_eq!(Point::new(2.0, -3.0).clamp(min, max), Point::new(2.0, 0.0));
+    assert_eq!(Point::new(9.0, -9.0).clamp(min, max), Point::new(4.0, 0.0));
+    // Points on the boundary stay there
+    assert_eq!(Point::new(4.0, 2.0).clamp(min, max), Point::new(4.0, 2.0));
+    assert_eq!(Point::new(0.0, 1.0).clamp(min, max), Point::new(0.0, 1.0));
+}
+
+#[test]
+#[should_panic]
+fn point_clamp_empty_box() {
+    let _ = Point::new(1.0, 1.0).clamp(Point::new(0.0, 2.0), Point::new(4.0, 0.0));
+}
+
+#[test]
+fn point_polar() {
+    let one = Finite::from_inner(1.0);
+    let east: Point<f64> = Point::polar(one, Angle::new(0.0));
+    let north: Point<f64> = Point::polar(one, Angle::new(PI / 2.0));
+    let west: Point<f64> = Point::polar(one, Angle::new(PI));
+    assert_abs_diff_eq!(east, Point::new(1.0, 0.0), epsilon = 1e-10);
+    assert_abs_diff_eq!(north, Point::new(0.0, 1.0), epsilon = 1e-10);
+    assert_abs_diff_eq!(west, Point::new(-1.0, 0.0), epsilon = 1e-10);
+
+    let center = Point::new(2.0, 3.0);
+    let about = Point::polar_about(center, one, Angle::new(PI / 2.0));
+    assert_abs_diff_eq!(about, Point::new(2.0, 4.0), epsilon = 1e-10);
+}
+
+#[test]
+fn point_nearest() {
+    let candidates = vec![
+        Point::new(0.0, 0.0),
+        Point::new(3.0, 1.0),
+        Point::new(-2.0, 4.0),
+    ];
+    let query = Point::new(2.0, 2.0);
+    assert_eq!(query.nearest(&candidates), Some(&candidates[1]));
+    assert_eq!(Point::new(-1.0, 3.0).nearest(candidates.iter()), Some(&candidates[2]));
+    assert_eq!(query.nearest(&Vec::new()), None);
+}
+
+#[test]
+fn point_map_value() {
+    let point: Point<f64> = Point::new(1.25, -3.1);
+    let narrow: Point<f32> = point.map_value();
+    assert_eq!(narrow.x.into_inner(), 1.25_f32);
+    assert_abs_diff_eq!(narrow.y.into_inner(), -3.1_f32, epsilon = 1e-6);
+    let wide: Point<f64> = narrow.map_value();
+    assert_abs_diff_eq!(wide, point, epsilon = 1e-6);
+
+    let delta: Delta<f64> = Delta::new(0.1, 2.0);
+    let back: Delta<f64> = delta.map_value::<f32>().map_value();
+    assert_abs_diff_eq!(back.dx.into_inner(), 0.1, epsilon = 1e-6);
+    assert_eq!(back.dy.into_inner(), 2.0);
+}
+
+#[test]
+fn point_relative_eq() {
+    // Apart by one part in a trillion, which is far more than any absolute epsilon
+    // tight enough to be useful near the origin
+    let a = Point::new(1.0e9, -3.0e9);
+    let b = Point::new(1.0e9 + 1.0e-3, -3.0e9 - 3.0e-3);
+    assert_abs_diff_ne!(a, b, epsilon = 1e-6);
+    assert_relative_eq!(a, b, max_relative = 1e-10);
+    assert_relative_ne!(a, b, max_relative = 1e-14);
+    assert_relative_ne!(a, Point::new(1.0e9, -3.1e9), max_relative = 1e-10);
+}
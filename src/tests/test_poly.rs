@@ -1,3 +1,21 @@
+use std::borrow::Cow;
+use std::f64::consts::PI;
+
+use decorum::Finite;
+
+use crate::geometry::arc::Arc;
+use crate::geometry::bounds::{BoundingBox, Rect};
+use crate::geometry::error::ErrorKind;
+use crate::geometry::line::Line;
+use crate::geometry::path::{Path, PathSegment};
+use crate::geometry::poly::{
+    Curved, OffsetHint, Polyarc, Polycurve, Polygon, Polyline, Segmented, SnapTarget,
+};
+use crate::geometry::prepared::PreparedPolygon;
+use crate::geometry::transform::Affine;
+use crate::geometry::*;
+use crate::tests::{assert_area_eq, assert_polygons_cover_area};
+
 #[ignore]
 #[test]
 fn test_offset_polyline() {
@@ -10,14 +28,1785 @@ fn test_offset_polygon() {
     todo!();
 }
 
-#[ignore]
 #[test]
 fn test_offset_polyarc() {
-    todo!();
+    // Corners which don't turn keep their curve size
+    let polyarc = curved_polyline(&[(0.0, 0.0), (2.0, 0.0), (5.0, 0.0)], 1.0);
+    let offset = polyarc.offset(Finite::from_inner(0.5));
+    assert_polyarc_eq(&offset, &[(0.0, -0.5), (2.0, -0.5), (5.0, -0.5)], &[1.0]);
 }
 
-#[ignore]
 #[test]
 fn test_offset_polycurve() {
-    todo!();
+    // A square with rounded corners, outset by 1
+    let polycurve = square(0.0, 0.0, 4.0).curve(Finite::from_inner(1.0));
+    let offset = polycurve.offset(Finite::from_inner(1.0));
+    assert_polycurve_eq(
+        &offset,
+        &[(-1.0, -1.0), (5.0, -1.0), (5.0, 5.0), (-1.0, 5.0)],
+        &[2.0, 2.0, 2.0, 2.0],
+    );
+}
+
+#[test]
+fn polygon_project_point() {
+    let square: Polygon<f64> = Polygon::new(vec![
+        Point::new(0.0, 0.0),
+        Point::new(2.0, 0.0),
+        Point::new(2.0, 2.0),
+        Point::new(0.0, 2.0),
+    ])
+    .unwrap();
+
+    // Outside, nearest to the right edge
+    let (point, index, distance) = square.project_point(Point::new(3.0, 1.5));
+    assert_abs_diff_eq!(point, Point::new(2.0, 1.5), epsilon = 1e-10);
+    assert_eq!(index, 1);
+    assert_abs_diff_eq!(distance.into_inner(), 1.0, epsilon = 1e-10);
+
+    // Inside, nearest to the bottom edge
+    let (point, index, distance) = square.project_point(Point::new(1.0, 0.25));
+    assert_abs_diff_eq!(point, Point::new(1.0, 0.0), epsilon = 1e-10);
+    assert_eq!(index, 0);
+    assert_abs_diff_eq!(distance.into_inner(), 0.25, epsilon = 1e-10);
+
+    // Outside a corner, projects onto the corner itself
+    let (point, _, distance) = square.project_point(Point::new(-1.0, 3.0));
+    assert_abs_diff_eq!(point, Point::new(0.0, 2.0), epsilon = 1e-10);
+    assert_abs_diff_eq!(distance.into_inner(), 2.0_f64.sqrt(), epsilon = 1e-10);
+}
+
+#[test]
+fn polygon_new_invalid() {
+    let points: Vec<Point<f64>> = vec![Point::new(0.0, 0.0), Point::new(1.0, 0.0)];
+    assert!(Polygon::new(points).is_err());
+    let points: Vec<Point<f64>> = vec![
+        Point::new(0.0, 0.0),
+        Point::new(1.0, 0.0),
+        Point::new(1.0, 1.0),
+        Point::new(0.0, 0.0),
+    ];
+    assert!(Polygon::new(points).is_err());
+}
+
+fn curved_polyline(points: &[(f64, f64)], curve_size: f64) -> Polyarc<f64> {
+    let points = points.iter().map(|&(x, y)| Point::new(x, y)).collect();
+    Polyline::new(points).unwrap().curve(Finite::from_inner(curve_size))
+}
+
+fn assert_polyarc_eq(polyarc: &Polyarc<f64>, points: &[(f64, f64)], curve_sizes: &[f64]) {
+    let actual = polyarc.polyline().points();
+    assert_eq!(actual.len(), points.len());
+    for (&actual, &(x, y)) in actual.iter().zip(points) {
+        assert_abs_diff_eq!(actual, Point::new(x, y), epsilon = 1e-10);
+    }
+    assert_eq!(polyarc.curve_sizes().len(), curve_sizes.len());
+    for (actual, &expected) in polyarc.curve_sizes().iter().zip(curve_sizes) {
+        assert_abs_diff_eq!(actual.into_inner(), expected, epsilon = 1e-10);
+    }
+}
+
+fn assert_polycurve_eq(
+    polycurve: &Polycurve<f64>,
+    points: &[(f64, f64)],
+    curve_sizes: &[f64],
+) {
+    let actual = polycurve.polygon().points();
+    assert_eq!(actual.len(), points.len());
+    for (&actual, &(x, y)) in actual.iter().zip(points) {
+        assert_abs_diff_eq!(actual, Point::new(x, y), epsilon = 1e-10);
+    }
+    assert_eq!(polycurve.curve_sizes().len(), curve_sizes.len());
+    for (actual, &expected) in polycurve.curve_sizes().iter().zip(curve_sizes) {
+        assert_abs_diff_eq!(actual.into_inner(), expected, epsilon = 1e-10);
+    }
+}
+
+fn l_shape() -> Polygon<f64> {
+    Polygon::new(vec![
+        Point::new(0.0, 0.0),
+        Point::new(2.0, 0.0),
+        Point::new(2.0, 1.0),
+        Point::new(1.0, 1.0),
+        Point::new(1.0, 2.0),
+        Point::new(0.0, 2.0),
+    ])
+    .unwrap()
+}
+
+#[test]
+fn polygon_triangulate() {
+    let triangles = l_shape().triangulate().unwrap();
+    assert_eq!(triangles.len(), 4);
+    for triangle in &triangles {
+        assert_eq!(triangle.points().len(), 3);
+        assert_eq!(triangle.orientation(), Direction::Counterclockwise);
+    }
+    assert_polygons_cover_area(&triangles, 3.0, 1e-10);
+}
+
+#[test]
+fn polygon_decompose_convex() {
+    let pieces = l_shape().decompose_convex().unwrap();
+    assert_eq!(pieces.len(), 2);
+    for piece in &pieces {
+        assert_eq!(piece.decompose_convex().unwrap().len(), 1);
+    }
+    assert_polygons_cover_area(&pieces, 3.0, 1e-10);
+
+    // Clockwise input gives the same pieces, wound counterclockwise
+    let mut points = l_shape().points().clone();
+    points.reverse();
+    let pieces = Polygon::new(points).unwrap().decompose_convex().unwrap();
+    assert_eq!(pieces.len(), 2);
+    for piece in &pieces {
+        assert_eq!(piece.orientation(), Direction::Counterclockwise);
+    }
+}
+
+fn unit_square() -> Polygon<f64> {
+    Polygon::new(vec![
+        Point::new(0.0, 0.0),
+        Point::new(1.0, 0.0),
+        Point::new(1.0, 1.0),
+        Point::new(0.0, 1.0),
+    ])
+    .unwrap()
+}
+
+#[test]
+fn polygon_offset_square() {
+    let outset = unit_square().offset(Finite::from_inner(0.5)).unwrap();
+    let expected = [(-0.5, -0.5), (1.5, -0.5), (1.5, 1.5), (-0.5, 1.5)];
+    for (point, (x, y)) in outset.points().iter().zip(expected) {
+        assert_abs_diff_eq!(*point, Point::new(x, y), epsilon = 1e-10);
+    }
+    let inset = unit_square().offset(Finite::from_inner(-0.25)).unwrap();
+    let expected = [(0.25, 0.25), (0.75, 0.25), (0.75, 0.75), (0.25, 0.75)];
+    for (point, (x, y)) in inset.points().iter().zip(expected) {
+        assert_abs_diff_eq!(*point, Point::new(x, y), epsilon = 1e-10);
+    }
+}
+
+#[test]
+fn polygon_inflate_thin_rectangle() {
+    // Insets as far as the circle of the same area would collapse this long ago
+    let thin = Polygon::from_rectangle(
+        Point::new(0.0, 0.0),
+        Finite::from_inner(10.0),
+        Finite::from_inner(1.0),
+    )
+    .unwrap();
+    let deflated = thin.inflate_to_area(Finite::from_inner(5.0)).unwrap();
+    assert_area_eq(&deflated, 5.0, 1e-6);
+    let inset = (22.0 - 404.0_f64.sqrt()) / 8.0;
+    assert_abs_diff_eq!(deflated.points()[0], Point::new(inset, inset), epsilon = 1e-6);
+    let deflated = thin.as_cw().inflate_to_area(Finite::from_inner(0.01)).unwrap();
+    assert_area_eq(&deflated, 0.01, 1e-6);
+}
+
+#[test]
+fn polygon_offset_collapse() {
+    let thin = Polygon::from_rectangle(
+        Point::new(0.0, 0.0),
+        Finite::from_inner(10.0),
+        Finite::from_inner(1.0),
+    )
+    .unwrap();
+    let inset = thin.clone().offset(Finite::from_inner(-0.4)).unwrap();
+    let expected = [(0.4, 0.4), (9.6, 0.4), (9.6, 0.6), (0.4, 0.6)];
+    for (point, (x, y)) in inset.points().iter().zip(expected) {
+        assert_abs_diff_eq!(*point, Point::new(x, y), epsilon = 1e-10);
+    }
+    let error = thin.clone().offset(Finite::from_inner(-0.6)).unwrap_err();
+    assert_eq!(error.kind, ErrorKind::Collapsed);
+    // No inset distance panics, however far past collapse
+    for step in 1..=100 {
+        let result = thin.clone().offset(Finite::from_inner(-0.05 * step as f64));
+        assert_eq!(result.is_ok(), step < 10);
+    }
+}
+
+#[test]
+fn polygon_offset_collinear_vertex() {
+    let polygon = Polygon::new(vec![
+        Point::new(0.0, 0.0),
+        Point::new(1.0, 0.0),
+        Point::new(2.0, 0.0),
+        Point::new(2.0, 2.0),
+        Point::new(0.0, 2.0),
+    ])
+    .unwrap();
+    let outset = polygon.clone().offset(Finite::from_inner(0.1)).unwrap();
+    // The collinear vertex stays, moved out along with the line it sits on
+    let expected = [(-0.1, -0.1), (1.0, -0.1), (2.1, -0.1), (2.1, 2.1), (-0.1, 2.1)];
+    assert_eq!(outset.points().len(), expected.len());
+    for (point, (x, y)) in outset.points().iter().zip(expected) {
+        assert_abs_diff_eq!(*point, Point::new(x, y), epsilon = 1e-10);
+    }
+    let inset = polygon.offset(Finite::from_inner(-0.1)).unwrap();
+    assert_area_eq(&inset, 1.8 * 1.8, 1e-10);
+}
+
+#[test]
+fn area_assertions() {
+    assert_area_eq(&unit_square(), 1.0, 1e-10);
+    assert_area_eq(&unit_square().as_cw(), 1.0, 1e-10);
+    assert_polygons_cover_area(&[unit_square(), l_shape().as_cw()], 4.0, 1e-10);
+}
+
+#[test]
+#[should_panic(expected = "Polygon has area 1, not 2")]
+fn area_assertion_fails() {
+    assert_area_eq(&unit_square(), 2.0, 1e-10);
+}
+
+#[test]
+fn polygon_offset_closing_corner() {
+    // The corner closing the polygon joins the last edge back to the first, which must
+    // only be offset once
+    let triangle =
+        Polygon::new(vec![Point::new(0.0, 0.0), Point::new(4.0, 0.0), Point::new(0.0, 3.0)])
+            .unwrap();
+    let outset = triangle.offset(Finite::from_inner(1.0)).unwrap();
+    let expected = [(-1.0, -1.0), (7.0, -1.0), (-1.0, 5.0)];
+    assert_eq!(outset.points().len(), expected.len());
+    for (point, (x, y)) in outset.points().iter().zip(expected) {
+        assert_abs_diff_eq!(*point, Point::new(x, y), epsilon = 1e-10);
+    }
+}
+
+#[test]
+fn polygon_inflate_to_area() {
+    let inflated = unit_square().inflate_to_area(Finite::from_inner(4.0)).unwrap();
+    assert_area_eq(&inflated, 4.0, 1e-6);
+    assert_abs_diff_eq!(inflated.points()[0], Point::new(-0.5, -0.5), epsilon = 1e-6);
+
+    let deflated = unit_square().inflate_to_area(Finite::from_inner(0.25)).unwrap();
+    assert_area_eq(&deflated, 0.25, 1e-6);
+    assert_abs_diff_eq!(deflated.points()[0], Point::new(0.25, 0.25), epsilon = 1e-6);
+
+    // Clockwise polygons are grown by negative offsets instead
+    let mut points = unit_square().points().clone();
+    points.reverse();
+    let clockwise = Polygon::new(points).unwrap();
+    let inflated = clockwise.inflate_to_area(Finite::from_inner(4.0)).unwrap();
+    assert_abs_diff_eq!(inflated.signed_area().into_inner(), -4.0, epsilon = 1e-6);
+
+    let l_inflated = l_shape().inflate_to_area(Finite::from_inner(2.0)).unwrap();
+    assert_area_eq(&l_inflated, 2.0, 1e-6);
+
+    assert!(unit_square().inflate_to_area(Finite::from_inner(0.0)).is_err());
+}
+
+#[test]
+fn polygon_new_ccw() {
+    let points = vec![
+        Point::new(0.0, 0.0),
+        Point::new(0.0, 1.0),
+        Point::new(1.0, 1.0),
+        Point::new(1.0, 0.0),
+    ];
+    let clockwise = Polygon::new(points.clone()).unwrap();
+    assert_eq!(clockwise.orientation(), Direction::Clockwise);
+
+    let polygon = Polygon::new_ccw(points).unwrap();
+    assert_eq!(polygon.orientation(), Direction::Counterclockwise);
+    assert_abs_diff_eq!(polygon.signed_area().into_inner(), 1.0, epsilon = 1e-10);
+
+    // Already counterclockwise polygons are unchanged.
+    let square = unit_square();
+    assert_eq!(square.as_ccw().points(), square.points());
+    assert_eq!(square.as_cw().orientation(), Direction::Clockwise);
+    assert_abs_diff_eq!(square.as_cw().signed_area().into_inner(), -1.0, epsilon = 1e-10);
+}
+
+#[test]
+fn polygon_check_offset_orientation() {
+    let one = Finite::from_inner(1.0);
+    let ccw = unit_square();
+    let cw = ccw.as_cw();
+    assert_eq!(ccw.check_offset_orientation(one), OffsetHint::WillGrow);
+    assert_eq!(ccw.check_offset_orientation(-one), OffsetHint::WillShrink);
+    assert_eq!(cw.check_offset_orientation(one), OffsetHint::WillShrink);
+    assert_eq!(cw.check_offset_orientation(-one), OffsetHint::WillGrow);
+
+    // The hint agrees with what offset actually does
+    let grown = ccw.clone().offset(Finite::from_inner(0.1)).unwrap();
+    assert!(grown.area() > ccw.area());
+    let shrunk = cw.clone().offset(Finite::from_inner(0.1)).unwrap();
+    assert!(shrunk.area() < cw.area());
+
+    let zero = Finite::from_inner(0.0);
+    assert_eq!(ccw.check_offset_orientation(zero), OffsetHint::Ambiguous);
+    let flat = Polygon::new(vec![
+        Point::new(0.0, 0.0),
+        Point::new(1.0, 0.0),
+        Point::new(2.0, 0.0),
+    ])
+    .unwrap();
+    assert_eq!(flat.check_offset_orientation(one), OffsetHint::Ambiguous);
+}
+
+#[test]
+fn polygon_from_regular() {
+    let center = Point::new(1.0, -2.0);
+    let hexagon = Polygon::from_regular(center, Finite::from_inner(2.0), 6).unwrap();
+    assert_eq!(hexagon.orientation(), Direction::Counterclockwise);
+    assert_abs_diff_eq!(hexagon.points()[0], Point::new(3.0, -2.0), epsilon = 1e-10);
+    // Each side of a regular hexagon is as long as its radius, and turns 60 degrees
+    for line in hexagon.to_segments() {
+        assert_abs_diff_eq!(line.length().into_inner(), 2.0, epsilon = 1e-10);
+    }
+    for (index, &corner) in hexagon.points().iter().enumerate() {
+        let prev = hexagon.points()[(index + 5) % 6];
+        let next = hexagon.points()[(index + 1) % 6];
+        let interior = (prev - corner).angle() - (next - corner).angle();
+        assert_abs_diff_eq!(
+            Angle::from(interior).radians().into_inner(),
+            2.0 * PI / 3.0,
+            epsilon = 1e-10
+        );
+    }
+    assert_area_eq(&hexagon, 6.0 * 3.0_f64.sqrt(), 1e-10);
+    assert_abs_diff_eq!(hexagon.centroid(), center, epsilon = 1e-10);
+
+    let circle = Polygon::from_circle_approximation(center, Finite::from_inner(1.0), 256);
+    assert_area_eq(&circle.unwrap(), PI, 1e-3);
+    assert!(Polygon::from_regular(center, Finite::from_inner(1.0), 2).is_err());
+    assert!(Polygon::from_regular(center, Finite::from_inner(0.0), 5).is_err());
+}
+
+#[test]
+fn polygon_from_rectangle() {
+    let corner = Point::new(1.0, 2.0);
+    let rectangle =
+        Polygon::from_rectangle(corner, Finite::from_inner(3.0), Finite::from_inner(2.0))
+            .unwrap();
+    assert_eq!(
+        rectangle.points(),
+        &[
+            Point::new(1.0, 2.0),
+            Point::new(4.0, 2.0),
+            Point::new(4.0, 4.0),
+            Point::new(1.0, 4.0),
+        ]
+    );
+    assert_eq!(rectangle.orientation(), Direction::Counterclockwise);
+    let zero = Finite::from_inner(0.0);
+    assert!(Polygon::from_rectangle(corner, zero, Finite::from_inner(2.0)).is_err());
+}
+
+#[test]
+fn polyline_trim_at_boundary() {
+    let barrier = unit_square();
+    // Runs along below the square, then turns up into it through its bottom edge
+    let entering = Polyline::new(vec![
+        Point::new(-1.0, -1.0),
+        Point::new(0.5, -1.0),
+        Point::new(0.5, 0.5),
+        Point::new(0.5, 3.0),
+    ])
+    .unwrap();
+    let trimmed = entering.trim_at_boundary(&barrier);
+    assert_eq!(trimmed.points().len(), 3);
+    assert_eq!(trimmed.points()[..2], entering.points()[..2]);
+    assert_abs_diff_eq!(trimmed.points()[2], Point::new(0.5, 0.0), epsilon = 1e-10);
+
+    // Through the square within one segment, stopping where it first enters
+    let through = Polyline::new(vec![Point::new(2.0, 0.5), Point::new(-1.0, 0.5)]).unwrap();
+    let trimmed = through.trim_at_boundary(&barrier);
+    assert_abs_diff_eq!(trimmed.points()[1], Point::new(1.0, 0.5), epsilon = 1e-10);
+
+    let missing = Polyline::new(vec![
+        Point::new(-1.0, -1.0),
+        Point::new(2.0, -1.0),
+        Point::new(2.0, 2.0),
+    ])
+    .unwrap();
+    assert_eq!(missing.trim_at_boundary(&barrier).points(), missing.points());
+}
+
+#[test]
+fn polygon_convex_hull() {
+    // The square's corners, with a notch cut in to a point inside it
+    let notched = Polygon::new(vec![
+        Point::new(0.0, 0.0),
+        Point::new(1.0, 0.0),
+        Point::new(1.0, 1.0),
+        Point::new(0.5, 0.5),
+        Point::new(0.0, 1.0),
+    ])
+    .unwrap();
+    assert_eq!(notched.convex_hull().unwrap().points(), unit_square().points());
+
+    // Already convex, so unchanged other than winding
+    let clockwise = unit_square().as_cw();
+    assert_eq!(clockwise.convex_hull().unwrap().points(), clockwise.as_ccw().points());
+
+    // Scattered points inside a diamond, with its corners and some repeated points
+    let mut seed: u32 = 12345;
+    let mut random = || {
+        seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+        f64::from(seed >> 8) / f64::from(1u32 << 24)
+    };
+    let corners = vec![
+        Point::new(0.0, -2.0),
+        Point::new(2.0, 0.0),
+        Point::new(0.0, 2.0),
+        Point::new(-2.0, 0.0),
+    ];
+    let mut cloud = corners.clone();
+    for _ in 0..50 {
+        let (a, b) = (random() - 0.5, random() - 0.5);
+        cloud.push(Point::new(a + b, a - b));
+    }
+    cloud.extend(corners.clone());
+    let hull = Polygon::new(cloud).unwrap().convex_hull().unwrap();
+    assert_eq!(hull.orientation(), Direction::Counterclockwise);
+    assert!(hull.is_convex());
+    let mut hull_points = hull.points().clone();
+    hull_points.sort();
+    let mut expected = corners;
+    expected.sort();
+    assert_eq!(hull_points, expected);
+
+    let collinear = Polygon::new(vec![
+        Point::new(0.0, 0.0),
+        Point::new(1.0, 1.0),
+        Point::new(3.0, 3.0),
+        Point::new(2.0, 2.0),
+    ])
+    .unwrap();
+    assert!(collinear.convex_hull().is_err());
+}
+
+#[test]
+fn polygon_to_f32() {
+    let polygon = Polygon::from_rectangle(
+        Point::new(0.1, -2.3),
+        Finite::from_inner(1.7),
+        Finite::from_inner(0.9),
+    )
+    .unwrap();
+    let narrow: Polygon<f32> = polygon.to_f32().unwrap();
+    let back: Polygon<f64> = narrow.cast().unwrap();
+    for (&before, &after) in polygon.points().iter().zip(back.points()) {
+        assert_abs_diff_eq!(before, after, epsilon = 1e-6);
+    }
+    assert_abs_diff_eq!(back.area().into_inner(), 1.53, epsilon = 1e-6);
+
+    let line = Line::new(Point::new(1.0, 2.0), Point::new(-3.0, 0.5)).unwrap();
+    let narrow_line = line.to_f32().unwrap();
+    assert_abs_diff_eq!(narrow_line.start(), Point::new(1.0, 2.0), epsilon = 1e-5);
+    assert_abs_diff_eq!(narrow_line.stop(), Point::new(-3.0, 0.5), epsilon = 1e-5);
+
+    let origin = Point::new(0.0, 0.0);
+    let arc = Arc::from_center(origin, Point::new(1.0, 0.0), Point::new(0.0, 1.0)).unwrap();
+    let narrow_arc = arc.to_f32().unwrap();
+    assert_abs_diff_eq!(narrow_arc.stop(), Point::new(0.0, 1.0), epsilon = 1e-6);
+
+    // Too large for f32
+    let error = square(0.0, 0.0, 1e40).to_f32().unwrap_err();
+    assert!(matches!(error.kind, ErrorKind::NonFiniteValue));
+    assert!(Point::new(1e300, 0.0).to_f32().is_err());
+    assert!(Delta::new(0.0, -1e300).to_f32().is_err());
+}
+
+#[test]
+fn polygon_inset_split() {
+    // Two lobes joined by a neck between notches, 0.4 across at its narrowest
+    let dumbbell = Polygon::new(vec![
+        Point::new(0.0, 0.0),
+        Point::new(2.0, 0.8),
+        Point::new(4.0, 0.0),
+        Point::new(4.0, 2.0),
+        Point::new(2.0, 1.2),
+        Point::new(0.0, 2.0),
+    ])
+    .unwrap();
+
+    // Not yet pinched off
+    let pieces = dumbbell.clone().inset_split(Finite::from_inner(0.1)).unwrap();
+    assert_eq!(pieces.len(), 1);
+    assert_eq!(pieces[0].points().len(), 6);
+
+    // Past the neck, one piece either side, wound the same way as the original
+    let pieces = dumbbell.clone().inset_split(Finite::from_inner(0.3)).unwrap();
+    assert_eq!(pieces.len(), 2);
+    for piece in &pieces {
+        assert_eq!(piece.orientation(), Direction::Counterclockwise);
+        assert!(dumbbell.contains_point(piece.centroid()));
+    }
+    let left = pieces.iter().filter(|piece| piece.centroid().x.into_inner() < 2.0);
+    assert_eq!(left.count(), 1);
+    let mirrored = |a: &Polygon<f64>, b: &Polygon<f64>| {
+        abs_diff_eq!(a.area().into_inner(), b.area().into_inner(), epsilon = 1e-10)
+    };
+    assert!(mirrored(&pieces[0], &pieces[1]));
+
+    // Winding the other way makes no difference
+    let reversed = dumbbell.as_cw().inset_split(Finite::from_inner(0.3)).unwrap();
+    assert_eq!(reversed.len(), 2);
+
+    // Nor does the scale of the polygon
+    let scale = Finite::from_inner(1e-5);
+    let tiny = dumbbell.points().iter().map(|&p| Point {
+        x: p.x * scale,
+        y: p.y * scale,
+    });
+    let tiny_dumbbell = Polygon::new(tiny.collect()).unwrap();
+    let pieces = tiny_dumbbell.inset_split(Finite::from_inner(0.3e-5)).unwrap();
+    assert_eq!(pieces.len(), 2);
+}
+
+#[test]
+fn polygon_inset_split_past_collapse() {
+    let thin = Polygon::from_rectangle(
+        Point::new(0.0, 0.0),
+        Finite::from_inner(10.0),
+        Finite::from_inner(1.0),
+    )
+    .unwrap();
+    let pieces = thin.clone().inset_split(Finite::from_inner(0.4)).unwrap();
+    assert_eq!(pieces.len(), 1);
+    assert_area_eq(&pieces[0], 9.2 * 0.2, 1e-10);
+    assert!(thin.inset_split(Finite::from_inner(0.6)).unwrap().is_empty());
+    let collinear = Polygon::new(vec![
+        Point::new(0.0, 0.0),
+        Point::new(1.0, 0.0),
+        Point::new(2.0, 0.0),
+        Point::new(2.0, 2.0),
+        Point::new(0.0, 2.0),
+    ])
+    .unwrap();
+    let pieces = collinear.clone().inset_split(Finite::from_inner(0.5)).unwrap();
+    assert_eq!(pieces.len(), 1);
+    assert!(collinear.inset_split(Finite::from_inner(1.5)).unwrap().is_empty());
+}
+
+#[test]
+fn polyline_simplify() {
+    // A semicircle of radius 0.1, through 100 points
+    let points: Vec<Point<f64>> = (0..100)
+        .map(|index| {
+            let angle = PI * index as f64 / 99.0;
+            Point::new(0.1 * angle.cos(), 0.1 * angle.sin())
+        })
+        .collect();
+    let semicircle = Polyline::new(points.clone()).unwrap();
+    let epsilon = Finite::from_inner(0.01);
+    let simplified = semicircle.simplify(epsilon);
+    let kept = simplified.points();
+    assert!(kept.len() < 10);
+    assert_eq!(kept[0], points[0]);
+    assert_eq!(kept[kept.len() - 1], points[99]);
+    // Every point left out is still close to the simplified path
+    let segments = simplified.to_segments();
+    for &point in &points {
+        let distance = segments
+            .iter()
+            .map(|segment| segment.distance_to_point(point))
+            .min()
+            .unwrap();
+        assert!(distance <= epsilon);
+    }
+    // Straight runs lose all their interior points
+    let straight = Polyline::new(vec![
+        Point::new(0.0, 0.0),
+        Point::new(1.0, 0.001),
+        Point::new(2.0, 0.0),
+        Point::new(2.0, 1.0),
+    ])
+    .unwrap();
+    assert_eq!(
+        straight.simplify(epsilon).points(),
+        &[Point::new(0.0, 0.0), Point::new(2.0, 0.0), Point::new(2.0, 1.0)]
+    );
+}
+
+#[test]
+fn polygon_simplify() {
+    let circle = Polygon::from_regular(Point::new(0.0, 0.0), Finite::from_inner(1.0), 200)
+        .unwrap();
+    let simplified = circle.simplify(Finite::from_inner(0.01));
+    assert!(simplified.points().len() < 40);
+    assert_eq!(simplified.points()[0], circle.points()[0]);
+    assert_eq!(simplified.orientation(), Direction::Counterclockwise);
+    assert_area_eq(&simplified, PI, 0.05);
+
+    // Points along the square's edges are left out
+    let mut points = Vec::new();
+    for line in unit_square().to_segments() {
+        points.push(line.start());
+        points.push(line.start().midpoint(line.stop()));
+    }
+    let square = Polygon::new(points).unwrap();
+    let simplified = square.simplify(Finite::from_inner(0.01));
+    assert_eq!(simplified.points().len(), 4);
+    assert_area_eq(&simplified, 1.0, 1e-10);
+
+    // A sliver within epsilon of a line still keeps three points
+    let sliver = Polygon::new(vec![
+        Point::new(0.0, 0.0),
+        Point::new(1.0, 0.0),
+        Point::new(2.0, 0.0),
+        Point::new(1.0, 0.001),
+    ])
+    .unwrap();
+    let simplified = sliver.simplify(Finite::from_inner(0.01));
+    assert_eq!(
+        simplified.points(),
+        &[Point::new(0.0, 0.0), Point::new(2.0, 0.0), Point::new(1.0, 0.001)]
+    );
+}
+
+#[test]
+fn polygon_boundary_intersections() {
+    let a = square(0.0, 0.0, 2.0);
+    let b = square(1.0, 1.0, 2.0);
+    let crossings = a.boundary_intersections(&b);
+    assert_eq!(crossings.len(), 2);
+    // a's right edge crosses b's bottom edge, and a's top edge crosses b's left edge
+    let (self_edge, other_edge, point) = crossings[0];
+    assert_eq!((self_edge, other_edge), (1, 0));
+    assert_abs_diff_eq!(point, Point::new(2.0, 1.0), epsilon = 1e-10);
+    let (self_edge, other_edge, point) = crossings[1];
+    assert_eq!((self_edge, other_edge), (2, 3));
+    assert_abs_diff_eq!(point, Point::new(1.0, 2.0), epsilon = 1e-10);
+
+    assert!(a.boundary_intersections(&square(5.0, 0.0, 1.0)).is_empty());
+    // One inside the other, so the boundaries don't meet
+    assert!(a.boundary_intersections(&square(0.5, 0.5, 1.0)).is_empty());
+}
+
+#[test]
+fn polyline_smooth() {
+    let corner = Polyline::new(vec![
+        Point::new(0.0, 0.0),
+        Point::new(4.0, 0.0),
+        Point::new(4.0, 4.0),
+    ])
+    .unwrap();
+    assert_eq!(
+        corner.smooth(1).points(),
+        &[
+            Point::new(0.0, 0.0),
+            Point::new(3.0, 0.0),
+            Point::new(4.0, 1.0),
+            Point::new(4.0, 4.0),
+        ]
+    );
+    assert_eq!(corner.smooth(0).points(), corner.points());
+    // (n - 2) * 2^k + 2 points after k iterations
+    let points = (0..5).map(|x| Point::new(x as f64, (x % 2) as f64)).collect();
+    let zigzag = Polyline::new(points).unwrap();
+    assert_eq!(zigzag.smooth(3).points().len(), 3 * 8 + 2);
+}
+
+#[test]
+fn polygon_smooth() {
+    let center = Point::new(1.0, 1.0);
+    // How far the distance from the center strays from its mean along the boundary,
+    // relative to the mean, judging by the points and the middle of each edge
+    let roughness = |polygon: &Polygon<f64>| {
+        let distances: Vec<f64> = polygon
+            .to_segments()
+            .iter()
+            .flat_map(|edge| [edge.start(), edge.start().midpoint(edge.stop())])
+            .map(|point| point.distance(center).into_inner())
+            .collect();
+        let mean = distances.iter().sum::<f64>() / distances.len() as f64;
+        let spread = distances.iter().map(|distance| (distance - mean).abs());
+        spread.fold(0.0, f64::max) / mean
+    };
+    let hexagon = Polygon::from_regular(center, Finite::from_inner(1.0), 6).unwrap();
+    let smoothed = hexagon.smooth(1);
+    assert_eq!(smoothed.points().len(), 12);
+    assert!(roughness(&smoothed) < roughness(&hexagon));
+    let smoother = hexagon.smooth(3);
+    assert_eq!(smoother.points().len(), 48);
+    assert!(roughness(&smoother) < roughness(&smoothed));
+    assert_eq!(smoother.orientation(), Direction::Counterclockwise);
+}
+
+fn square(x: f64, y: f64, size: f64) -> Polygon<f64> {
+    Polygon::new(vec![
+        Point::new(x, y),
+        Point::new(x + size, y),
+        Point::new(x + size, y + size),
+        Point::new(x, y + size),
+    ])
+    .unwrap()
+}
+
+#[test]
+fn polygon_area_and_winding() {
+    let ccw = unit_square();
+    assert_abs_diff_eq!(ccw.signed_area().into_inner(), 1.0, epsilon = 1e-10);
+    assert_abs_diff_eq!(ccw.area().into_inner(), 1.0, epsilon = 1e-10);
+    assert!(ccw.is_counterclockwise());
+    assert!(!ccw.is_clockwise());
+
+    let cw = ccw.as_cw();
+    assert_abs_diff_eq!(cw.signed_area().into_inner(), -1.0, epsilon = 1e-10);
+    assert_abs_diff_eq!(cw.area().into_inner(), 1.0, epsilon = 1e-10);
+    assert!(cw.is_clockwise());
+    assert!(!cw.is_counterclockwise());
+
+    // Concave, with a notch cut from a 2 by 2 square
+    let l_shape = l_shape();
+    assert_abs_diff_eq!(l_shape.area().into_inner(), 3.0, epsilon = 1e-10);
+    assert_abs_diff_eq!(l_shape.signed_area().into_inner(), 3.0, epsilon = 1e-10);
+    assert!(l_shape.is_counterclockwise());
+}
+
+#[test]
+fn polygon_star() {
+    let center = Point::new(1.0, 2.0);
+    let (outer, inner) = (Finite::from_inner(2.0), Finite::from_inner(1.0));
+    let star = Polygon::star(center, outer, inner, 5, Angle::new(PI / 2.0)).unwrap();
+    assert_eq!(star.points().len(), 10);
+    assert!(!star.is_convex());
+    assert!(star.is_counterclockwise());
+    assert_abs_diff_eq!(star.points()[0], Point::new(1.0, 4.0), epsilon = 1e-10);
+    assert_abs_diff_eq!(star.centroid(), center, epsilon = 1e-10);
+    // Ten triangles between the center and each pair of corners
+    let area = 10.0 * 0.5 * 2.0 * 1.0 * (PI / 5.0).sin();
+    assert_abs_diff_eq!(star.area().into_inner(), area, epsilon = 1e-10);
+
+    assert!(Polygon::star(center, inner, outer, 5, Angle::new(0.0)).is_err());
+    assert!(Polygon::star(center, outer, inner, 2, Angle::new(0.0)).is_err());
+}
+
+#[test]
+fn polygon_gear() {
+    let center: Point<f64> = Point::new(0.0, 0.0);
+    let (pitch_radius, depth) = (Finite::from_inner(10.0), Finite::from_inner(2.0));
+    let gear = Polygon::gear(center, pitch_radius, 12, depth).unwrap();
+    assert_eq!(gear.points().len(), 48);
+    assert!(!gear.is_convex());
+    assert!(gear.is_counterclockwise());
+    for point in gear.points() {
+        let radius = point.distance(center).into_inner();
+        assert!((radius - 9.0).abs() < 1e-10 || (radius - 11.0).abs() < 1e-10);
+    }
+    // Twelve triangles out to the tips, and twelve to the roots between them
+    let area = 12.0 * 0.5 * (11.0 * 11.0 + 9.0 * 9.0) * (PI / 12.0).sin();
+    assert_abs_diff_eq!(gear.area().into_inner(), area, epsilon = 1e-10);
+
+    assert!(Polygon::gear(center, pitch_radius, 2, depth).is_err());
+    assert!(Polygon::gear(center, pitch_radius, 12, Finite::from_inner(0.0)).is_err());
+    assert!(Polygon::gear(center, pitch_radius, 12, Finite::from_inner(20.0)).is_err());
+}
+
+#[test]
+fn polygon_overlap_area() {
+    let a = unit_square();
+    let area = |a: &Polygon<f64>, b: &Polygon<f64>| a.overlap_area(b).unwrap().into_inner();
+
+    assert_abs_diff_eq!(area(&a, &a), 1.0, epsilon = 1e-10);
+    assert_abs_diff_eq!(area(&a, &square(0.5, 0.0, 1.0)), 0.5, epsilon = 1e-10);
+    assert_abs_diff_eq!(area(&a, &square(0.5, 0.5, 1.0)), 0.25, epsilon = 1e-10);
+    assert_abs_diff_eq!(area(&a, &square(2.0, 0.0, 1.0)), 0.0, epsilon = 1e-10);
+    // Squares which share an edge don't overlap.
+    assert_abs_diff_eq!(area(&a, &square(1.0, 0.0, 1.0)), 0.0, epsilon = 1e-10);
+    // Orientation doesn't matter, and neither do concave polygons.
+    let cw = a.as_cw();
+    assert_abs_diff_eq!(area(&cw, &square(0.5, 0.0, 1.0)), 0.5, epsilon = 1e-10);
+    assert_abs_diff_eq!(area(&l_shape(), &square(0.0, 0.0, 3.0)), 3.0, epsilon = 1e-10);
+    assert_abs_diff_eq!(area(&l_shape(), &square(0.5, 0.5, 1.0)), 0.75, epsilon = 1e-10);
+}
+
+#[test]
+fn polygon_may_intersect() {
+    let a = unit_square();
+    assert!(!a.may_intersect(&square(10.0, 10.0, 1.0)));
+    assert!(a.may_intersect(&square(0.5, 0.5, 1.0)));
+    assert!(a.may_intersect(&a));
+    // Touching counts, since the polygons can't be shown apart.
+    assert!(a.may_intersect(&square(1.0, 0.0, 1.0)));
+
+    // Diamonds whose bounding boxes overlap at the corners, but are far enough apart for
+    // their bounding circles not to.
+    let diamond = |x: f64, y: f64| {
+        let points = [[x + 1.0, y], [x + 2.0, y + 1.0], [x + 1.0, y + 2.0], [x, y + 1.0]];
+        Polygon::from_flat(&points).unwrap()
+    };
+    assert!(!diamond(0.0, 0.0).may_intersect(&diamond(1.9, 1.9)));
+    assert!(diamond(0.0, 0.0).may_intersect(&diamond(1.0, 1.0)));
+}
+
+#[test]
+fn polygon_fit_into() {
+    let wide = Polygon::new(vec![
+        Point::new(-2.0, 1.0),
+        Point::new(2.0, 1.0),
+        Point::new(2.0, 2.0),
+        Point::new(-2.0, 2.0),
+    ])
+    .unwrap();
+    let target = Rect {
+        min: Point::new(10.0, 10.0),
+        max: Point::new(20.0, 20.0),
+    };
+
+    let fitted = wide.fit_into(target, true).unwrap();
+    let bounds = BoundingBox::from_points(fitted.points().iter().copied()).unwrap();
+    assert_abs_diff_eq!(bounds.min, Point::new(10.0, 13.75), epsilon = 1e-10);
+    assert_abs_diff_eq!(bounds.max, Point::new(20.0, 16.25), epsilon = 1e-10);
+    assert_abs_diff_eq!(bounds.center(), target.center(), epsilon = 1e-10);
+
+    let stretched = wide.fit_into(target, false).unwrap();
+    let bounds = BoundingBox::from_points(stretched.points().iter().copied()).unwrap();
+    assert_abs_diff_eq!(bounds.min, target.min, epsilon = 1e-10);
+    assert_abs_diff_eq!(bounds.max, target.max, epsilon = 1e-10);
+}
+
+#[test]
+fn polygon_flat_round_trip() {
+    let square = unit_square();
+    let flat = square.to_flat();
+    assert_eq!(flat, vec![[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]);
+    let round_trip = Polygon::from_flat(&flat).unwrap();
+    assert_eq!(round_trip.points(), square.points());
+
+    let polyline = Polyline::from_flat(&flat).unwrap();
+    assert_eq!(polyline.to_flat(), flat);
+
+    assert!(Polygon::from_flat(&[[0.0, 0.0], [1.0, f64::NAN], [1.0, 1.0]]).is_err());
+    assert!(Polyline::from_flat(&[[0.0, 0.0], [f64::INFINITY, 1.0]]).is_err());
+    assert!(Polygon::from_flat(&flat[..2]).is_err());
+}
+
+#[test]
+fn polygon_transform() {
+    let square = unit_square();
+    match square.transform(&Affine::identity()).unwrap() {
+        | Cow::Borrowed(borrowed) => assert!(std::ptr::eq(borrowed, &square)),
+        | Cow::Owned(_) => panic!("Identity transformation copied the polygon"),
+    }
+    // Only exact identities are skipped, since rounding error in the transformation
+    // moves points in proportion to how far they are from the origin
+    let full_turn = Affine::rotation(Angle::new(PI)).then(Affine::rotation(Angle::new(PI)));
+    let turned = square.transform(&full_turn).unwrap();
+    assert!(matches!(turned, Cow::Owned(_)));
+    assert_abs_diff_eq!(turned.points()[2], Point::new(1.0, 1.0), epsilon = 1e-10);
+    let far = Polygon::new(vec![
+        Point::new(1e6, 1e6),
+        Point::new(2e6, 1e6),
+        Point::new(2e6, 2e6),
+    ])
+    .unwrap();
+    let nearly_one = Affine::scale(Delta::new(1.0 + 1e-8, 1.0 + 1e-8));
+    let scaled = far.transform(&nearly_one).unwrap();
+    assert!(matches!(scaled, Cow::Owned(_)));
+    let expected = Point::new(2e6 + 0.02, 2e6 + 0.02);
+    assert_abs_diff_eq!(scaled.points()[2], expected, epsilon = 1e-6);
+
+    let quarter_turn = Affine::rotation(Angle::new(PI / 2.0));
+    let transformation = quarter_turn.then(Affine::translation(Delta::new(1.0, 0.0)));
+    let moved = square.transform(&transformation).unwrap();
+    assert!(matches!(moved, Cow::Owned(_)));
+    assert_abs_diff_eq!(moved.points()[1], Point::new(1.0, 1.0), epsilon = 1e-10);
+    assert_abs_diff_eq!(moved.points()[2], Point::new(0.0, 1.0), epsilon = 1e-10);
+
+    let flatten = Affine::scale(Delta::new(1.0, 0.0));
+    assert!(square.transform(&flatten).is_err());
+    let polyline = Polyline::new(square.points().clone()).unwrap();
+    assert!(matches!(
+        polyline.transform(&Affine::identity()).unwrap(),
+        Cow::Borrowed(_)
+    ));
+}
+
+#[test]
+fn polygon_approximate_medial_axis() {
+    let rectangle = Polygon::new(vec![
+        Point::new(0.0, 0.0),
+        Point::new(10.0, 0.0),
+        Point::new(10.0, 2.0),
+        Point::new(0.0, 2.0),
+    ])
+    .unwrap();
+    let spacing = 0.25;
+    let skeleton = rectangle
+        .approximate_medial_axis(Finite::from_inner(spacing))
+        .unwrap();
+
+    // The central axis, and a branch to each corner.
+    let axis = [
+        Line::new(Point::new(1.0, 1.0), Point::new(9.0, 1.0)).unwrap(),
+        Line::new(Point::new(1.0, 1.0), Point::new(0.0, 0.0)).unwrap(),
+        Line::new(Point::new(1.0, 1.0), Point::new(0.0, 2.0)).unwrap(),
+        Line::new(Point::new(9.0, 1.0), Point::new(10.0, 0.0)).unwrap(),
+        Line::new(Point::new(9.0, 1.0), Point::new(10.0, 2.0)).unwrap(),
+    ];
+    for polyline in skeleton.iter() {
+        for &point in polyline.points() {
+            let distance = axis
+                .iter()
+                .map(|line| point.distance(line.nearest_point(point)).into_inner())
+                .fold(f64::INFINITY, f64::min);
+            assert_le!(distance, spacing);
+        }
+    }
+
+    let central = skeleton
+        .iter()
+        .find(|polyline| polyline.points().len() > 20)
+        .expect("No central axis");
+    let xs = central.points().iter().map(|point| point.x.into_inner());
+    assert_lt!(xs.clone().fold(f64::INFINITY, f64::min), 1.5);
+    assert_gt!(xs.fold(f64::NEG_INFINITY, f64::max), 8.5);
+    for point in central.points() {
+        assert_abs_diff_eq!(point.y.into_inner(), 1.0, epsilon = spacing);
+    }
+    assert_eq!(skeleton.len(), 5);
+
+    // Spacings which aren't positive, or are too fine to count the samples
+    for spacing in [0.0, -0.25, 1e-300] {
+        let spacing = Finite::from_inner(spacing);
+        assert!(rectangle.approximate_medial_axis(spacing).is_err());
+    }
+}
+
+#[test]
+fn polygon_offset_rounded() {
+    let rounded = unit_square().offset_rounded(Finite::from_inner(0.5)).unwrap();
+    let corners = [(-0.5, -0.5), (1.5, -0.5), (1.5, 1.5), (-0.5, 1.5)];
+    for (corner, expected) in rounded.polygon().points().iter().zip(corners.iter()) {
+        let expected = Point::new(expected.0, expected.1);
+        assert_abs_diff_eq!(*corner, expected, epsilon = 1e-10);
+    }
+    // A quarter circle of radius r replaces r of each line, as curve_size is r * tan(45deg).
+    for curve_size in rounded.curve_sizes() {
+        assert_abs_diff_eq!(curve_size.into_inner(), 0.5, epsilon = 1e-10);
+    }
+
+    // Insetting leaves the corners sharp.
+    let inset = unit_square().offset_rounded(Finite::from_inner(-0.25)).unwrap();
+    assert!(inset.curve_sizes().iter().all(|size| size.into_inner() == 0.0));
+    assert_area_eq(inset.polygon(), 0.25, 1e-10);
+
+    // Outsetting a concave corner leaves it sharp, and the other corners round.
+    let rounded = l_shape().offset_rounded(Finite::from_inner(0.25)).unwrap();
+    let sizes: Vec<f64> =
+        rounded.curve_sizes().iter().map(|size| size.into_inner()).collect();
+    assert_eq!(sizes[3], 0.0);
+    for (index, size) in sizes.iter().enumerate() {
+        if index != 3 {
+            assert_abs_diff_eq!(*size, 0.25, epsilon = 1e-10);
+        }
+    }
+    let concave_corner = rounded.polygon().points()[3];
+    assert_abs_diff_eq!(concave_corner, Point::new(1.25, 1.25), epsilon = 1e-10);
+
+    // Clockwise polygons outset with a negative offset.
+    let clockwise = unit_square().as_cw();
+    let rounded = clockwise.offset_rounded(Finite::from_inner(-0.5)).unwrap();
+    for curve_size in rounded.curve_sizes() {
+        assert_abs_diff_eq!(curve_size.into_inner(), 0.5, epsilon = 1e-10);
+    }
+
+    assert!(unit_square().offset_rounded(Finite::from_inner(-0.75)).is_err());
+}
+
+#[test]
+fn polygon_snap() {
+    let square = unit_square();
+    let radius = Finite::from_inner(0.2);
+
+    let target = square.snap(Point::new(1.1, 0.95), radius);
+    assert_eq!(target, Some(SnapTarget::Vertex(2)));
+    let target = square.snap(Point::new(0.55, 1.1), radius);
+    assert_eq!(target, Some(SnapTarget::EdgeMidpoint(2)));
+    // Vertices take priority, even over a nearer edge.
+    let target = square.snap(Point::new(0.15, 0.02), radius);
+    assert_eq!(target, Some(SnapTarget::Vertex(0)));
+
+    match square.snap(Point::new(-0.1, 0.3), radius) {
+        | Some(SnapTarget::OnEdge(index, point)) => {
+            assert_eq!(index, 3);
+            assert_abs_diff_eq!(point, Point::new(0.0, 0.3), epsilon = 1e-10);
+        }
+        | target => panic!("Expected to snap onto an edge, not {:?}", target),
+    }
+    assert_eq!(square.snap(Point::new(0.5, 0.5), radius), None);
+}
+
+#[test]
+fn polyarc_from_path() {
+    let first_line = Line::new(Point::new(0.0, 0.0), Point::new(1.0, 0.0)).unwrap();
+    let arc =
+        Arc::from_center(Point::new(1.0, 1.0), Point::new(1.0, 0.0), Point::new(2.0, 1.0))
+            .unwrap();
+    let second_line = Line::new(Point::new(2.0, 1.0), Point::new(2.0, 3.0)).unwrap();
+    let third_line = Line::new(Point::new(2.0, 3.0), Point::new(0.0, 3.0)).unwrap();
+    let path = Path::new(vec![
+        PathSegment::Line(first_line),
+        PathSegment::Arc(arc),
+        PathSegment::Line(second_line),
+        PathSegment::Line(third_line),
+    ])
+    .unwrap();
+
+    let polyarc = Polyarc::from_path(&path).unwrap();
+    let expected = [(0.0, 0.0), (2.0, 0.0), (2.0, 3.0), (0.0, 3.0)];
+    let points = polyarc.polyline().points();
+    assert_eq!(points.len(), expected.len());
+    for (point, (x, y)) in points.iter().zip(expected) {
+        assert_abs_diff_eq!(*point, Point::new(x, y), epsilon = 1e-10);
+    }
+    let curve_sizes = polyarc.curve_sizes();
+    assert_eq!(curve_sizes.len(), 2);
+    let arc_curve_size = arc.curve_size().into_inner();
+    assert_abs_diff_eq!(curve_sizes[0].into_inner(), arc_curve_size, epsilon = 1e-10);
+    assert_abs_diff_eq!(curve_sizes[0].into_inner(), 1.0, epsilon = 1e-10);
+    assert_eq!(curve_sizes[1].into_inner(), 0.0);
+}
+
+#[test]
+fn polyarc_from_invalid_path() {
+    let line = Line::new(Point::new(0.0, 0.0), Point::new(1.0, 0.0)).unwrap();
+    let arc =
+        Arc::from_center(Point::new(1.0, 1.0), Point::new(1.0, 0.0), Point::new(2.0, 1.0))
+            .unwrap();
+    let next_arc =
+        Arc::from_center(Point::new(3.0, 1.0), Point::new(2.0, 1.0), Point::new(3.0, 2.0))
+            .unwrap();
+
+    // Starting or stopping with an arc
+    let path = Path::new(vec![PathSegment::Arc(arc)]).unwrap();
+    assert!(Polyarc::from_path(&path).is_err());
+    let path = Path::new(vec![PathSegment::Line(line), PathSegment::Arc(arc)]).unwrap();
+    assert!(Polyarc::from_path(&path).is_err());
+
+    // Two arcs in a row
+    let path = Path::new(vec![
+        PathSegment::Line(line),
+        PathSegment::Arc(arc),
+        PathSegment::Arc(next_arc),
+    ])
+    .unwrap();
+    assert!(Polyarc::from_path(&path).is_err());
+
+    // Segments which don't connect
+    assert!(Path::new(vec![PathSegment::Arc(next_arc), PathSegment::Line(line)]).is_err());
+}
+
+#[test]
+fn polyarc_from_path_with_misfit_arc() {
+    let corner_path = |first: (f64, f64), center: (f64, f64), stop: (f64, f64)| {
+        let start = Point::new(first.0, first.1);
+        let stop = Point::new(stop.0, stop.1);
+        let center = Point::new(center.0, center.1);
+        let first_line = Line::new(Point::new(0.0, 0.0), start).unwrap();
+        let arc = Arc::from_center(center, start, stop).unwrap();
+        let second_line = Line::new(stop, stop + Delta::new(0.0, 2.0)).unwrap();
+        Path::new(vec![
+            PathSegment::Line(first_line),
+            PathSegment::Arc(arc),
+            PathSegment::Line(second_line),
+        ])
+        .unwrap()
+    };
+    // Tangent to both lines, around the corner at (2, 0)
+    let polyarc = Polyarc::from_path(&corner_path((1.0, 0.0), (1.0, 1.0), (2.0, 1.0)));
+    let polyarc = polyarc.unwrap();
+    assert_abs_diff_eq!(polyarc.polyline().points()[1], Point::new(2.0, 0.0));
+    assert_abs_diff_eq!(polyarc.curve_sizes()[0].into_inner(), 1.0, epsilon = 1e-10);
+    // Not tangent to the first line
+    let path = corner_path((1.0, 0.0), (2.0, 0.0), (2.0, 1.0));
+    assert!(Polyarc::from_path(&path).is_err());
+    // Tangent to the first line, but with a radius too big to be tangent to the second
+    let path = corner_path((1.0, 0.0), (1.0, 1.25), (2.0, 0.5));
+    assert!(Polyarc::from_path(&path).is_err());
+    // Tangent to both lines, but starting past the corner at (2, 0) and coming back
+    let path = corner_path((3.0, 0.0), (3.0, 1.0), (2.0, 1.0));
+    assert!(Polyarc::from_path(&path).is_err());
+}
+
+// Rounded rectangle from (0, 0) to (4, 2) with corners of radius 0.5, starting from the
+// arc at the bottom left.
+fn rounded_rectangle_path() -> Path<f64> {
+    let line = |x1, y1, x2, y2| {
+        PathSegment::Line(Line::new(Point::new(x1, y1), Point::new(x2, y2)).unwrap())
+    };
+    let arc = |cx, cy, x1, y1, x2, y2| {
+        PathSegment::Arc(
+            Arc::from_center(Point::new(cx, cy), Point::new(x1, y1), Point::new(x2, y2))
+                .unwrap(),
+        )
+    };
+    Path::new(vec![
+        arc(0.5, 0.5, 0.0, 0.5, 0.5, 0.0),
+        line(0.5, 0.0, 3.5, 0.0),
+        arc(3.5, 0.5, 3.5, 0.0, 4.0, 0.5),
+        line(4.0, 0.5, 4.0, 1.5),
+        arc(3.5, 1.5, 4.0, 1.5, 3.5, 2.0),
+        line(3.5, 2.0, 0.5, 2.0),
+        arc(0.5, 1.5, 0.5, 2.0, 0.0, 1.5),
+        line(0.0, 1.5, 0.0, 0.5),
+    ])
+    .unwrap()
+}
+
+#[test]
+fn polycurve_from_path() {
+    let path = rounded_rectangle_path();
+    let polycurve = Polycurve::from_path(&path).unwrap();
+    let expected = [(0.0, 0.0), (4.0, 0.0), (4.0, 2.0), (0.0, 2.0)];
+    let points = polycurve.polygon().points();
+    assert_eq!(points.len(), expected.len());
+    for (point, (x, y)) in points.iter().zip(expected) {
+        assert_abs_diff_eq!(*point, Point::new(x, y), epsilon = 1e-10);
+    }
+    let arcs = path.segments().iter().filter_map(|segment| match segment {
+        PathSegment::Arc(arc) => Some(arc),
+        _ => None,
+    });
+    for (curve_size, arc) in polycurve.curve_sizes().iter().zip(arcs) {
+        let arc_curve_size = arc.curve_size().into_inner();
+        assert_abs_diff_eq!(curve_size.into_inner(), arc_curve_size, epsilon = 1e-10);
+        assert_abs_diff_eq!(curve_size.into_inner(), 0.5, epsilon = 1e-10);
+    }
+}
+
+#[test]
+fn polycurve_from_invalid_path() {
+    // Not closed
+    let mut segments = rounded_rectangle_path().segments().clone();
+    segments.pop();
+    let path = Path::new(segments).unwrap();
+    assert!(Polycurve::from_path(&path).is_err());
+
+    // Too few corners
+    let segments = vec![
+        PathSegment::Line(Line::new(Point::new(0.0, 0.0), Point::new(1.0, 0.0)).unwrap()),
+        PathSegment::Line(Line::new(Point::new(1.0, 0.0), Point::new(0.0, 0.0)).unwrap()),
+    ];
+    let path = Path::new(segments).unwrap();
+    assert!(Polycurve::from_path(&path).is_err());
+
+    // Too few arcs, with two of the rounded corners cut off by lines
+    let mut segments = rounded_rectangle_path().segments().clone();
+    for index in [2, 4] {
+        if let PathSegment::Arc(arc) = segments[index] {
+            let line = Line::new(arc.start(), arc.stop()).unwrap();
+            segments[index] = PathSegment::Line(line);
+        }
+    }
+    let path = Path::new(segments).unwrap();
+    assert!(Polycurve::from_path(&path).is_err());
+
+    // Arcs which meet the lines but aren't tangent to them: one centered on the corner,
+    // biting into it, and one with too big a radius, cutting the corner flatter
+    for (index, center) in [(2, (4.0, 0.0)), (4, (3.0, 1.0))] {
+        let mut segments = rounded_rectangle_path().segments().clone();
+        if let PathSegment::Arc(arc) = segments[index] {
+            let center = Point::new(center.0, center.1);
+            let arc = Arc::from_center(center, arc.start(), arc.stop()).unwrap();
+            segments[index] = PathSegment::Arc(arc);
+        }
+        let path = Path::new(segments).unwrap();
+        assert!(Polycurve::from_path(&path).is_err());
+    }
+}
+
+#[test]
+fn polyline_spiral() {
+    let center = Point::new(1.0, -1.0);
+    let spiral = Polyline::spiral(
+        center,
+        Finite::from_inner(0.5),
+        Finite::from_inner(1.0),
+        3,
+        16,
+    )
+    .unwrap();
+    let points = spiral.points();
+    assert_eq!(points.len(), 3 * 16 + 1);
+    assert_abs_diff_eq!(points[0], Point::new(1.5, -1.0), epsilon = 1e-10);
+    assert_abs_diff_eq!(points[48], Point::new(4.5, -1.0), epsilon = 1e-10);
+    for pair in points.windows(2) {
+        assert_lt!(pair[0].distance(center), pair[1].distance(center));
+    }
+
+    let zero = Finite::from_inner(0.0);
+    assert!(Polyline::spiral(center, zero, zero, 3, 16).is_err());
+}
+
+#[test]
+fn polyline_segments_keep_direction() {
+    // Each segment goes down and to the left
+    let points = vec![Point::new(3.0, 2.0), Point::new(1.0, -1.0), Point::new(-2.0, -1.5)];
+    let polyline = Polyline::new(points.clone()).unwrap();
+    let first = polyline.iter_segments().next().unwrap();
+    assert_abs_diff_eq!(first.start(), points[0], epsilon = 1e-10);
+    assert_abs_diff_eq!(first.stop(), points[1], epsilon = 1e-10);
+    for (line, pair) in polyline.iter_segments().zip(points.windows(2)) {
+        assert_abs_diff_eq!(line.start(), pair[0], epsilon = 1e-10);
+        assert_abs_diff_eq!(line.stop(), pair[1], epsilon = 1e-10);
+    }
+
+    let polygon = Polygon::new(points.clone()).unwrap();
+    let last = polygon.iter_segments().last().unwrap();
+    assert_abs_diff_eq!(last.start(), points[2], epsilon = 1e-10);
+    assert_abs_diff_eq!(last.stop(), points[0], epsilon = 1e-10);
+}
+
+#[test]
+fn polyline_smooth_moving_average() {
+    let spike = Polyline::from_flat(&[
+        [0.0, 0.0],
+        [1.0, 0.0],
+        [2.0, 0.0],
+        [3.0, 3.0],
+        [4.0, 0.0],
+        [5.0, 0.0],
+        [6.0, 0.0],
+    ])
+    .unwrap();
+    assert_eq!(spike.smooth_moving_average(1).points(), spike.points());
+
+    let smoothed = spike.smooth_moving_average(3);
+    let points = smoothed.points();
+    assert_eq!(points.len(), 7);
+    assert_eq!(points[0], spike.points()[0]);
+    assert_eq!(points[6], spike.points()[6]);
+    assert_abs_diff_eq!(points[3], Point::new(3.0, 1.0), epsilon = 1e-10);
+    // The spike is spread onto its neighbors
+    assert_abs_diff_eq!(points[2], Point::new(2.0, 1.0), epsilon = 1e-10);
+    assert_abs_diff_eq!(points[1], Point::new(1.0, 0.0), epsilon = 1e-10);
+
+    // Near the ends, the window narrows to stay centered
+    let smoothed = spike.smooth_moving_average(5);
+    let points = smoothed.points();
+    assert_abs_diff_eq!(points[1], Point::new(1.0, 0.0), epsilon = 1e-10);
+    assert_abs_diff_eq!(points[3], Point::new(3.0, 0.6), epsilon = 1e-10);
+}
+
+#[test]
+fn polyline_tangent_angles() {
+    // Right along the x axis, then turning left up the y axis
+    let points = vec![Point::new(0.0, 0.0), Point::new(2.0, 0.0), Point::new(2.0, 3.0)];
+    let tangents = Polyline::new(points).unwrap().tangent_angles();
+    assert_eq!(tangents.len(), 3);
+    assert_abs_diff_eq!(tangents[0].radians().into_inner(), 0.0, epsilon = 1e-10);
+    assert_abs_diff_eq!(tangents[1].radians().into_inner(), PI / 4.0, epsilon = 1e-10);
+    assert_abs_diff_eq!(tangents[2].radians().into_inner(), PI / 2.0, epsilon = 1e-10);
+
+    // The mean is circular, so turning across angle 0 still bisects the turn
+    let points = vec![Point::new(0.0, 1.0), Point::new(1.0, 0.0), Point::new(2.0, 1.0)];
+    let tangents = Polyline::new(points).unwrap().tangent_angles();
+    assert_abs_diff_eq!(tangents[1].radians().into_inner(), 0.0, epsilon = 1e-10);
+}
+
+#[test]
+fn polygon_contains_point() {
+    let square = unit_square();
+    assert!(square.contains_point(Point::new(0.5, 0.5)));
+    assert!(!square.contains_point(Point::new(1.5, 0.5)));
+    assert!(!square.contains_point(Point::new(0.5, -0.5)));
+    // On the boundary, including at the corners
+    assert!(square.contains_point(Point::new(1.0, 0.5)));
+    assert!(square.contains_point(Point::new(0.5, 0.0)));
+    assert!(square.contains_point(Point::new(0.0, 0.0)));
+    assert!(square.contains_point(Point::new(1.0, 1.0)));
+
+    // The L-shape is missing its upper right quarter
+    let l_shape = l_shape();
+    assert!(l_shape.contains_point(Point::new(0.5, 1.5)));
+    assert!(l_shape.contains_point(Point::new(1.5, 0.5)));
+    assert!(!l_shape.contains_point(Point::new(1.5, 1.5)));
+    assert!(l_shape.contains_point(Point::new(1.0, 1.0)));
+    assert!(l_shape.contains_point(Point::new(1.5, 1.0)));
+    assert!(!l_shape.contains_point(Point::new(1.5, 1.0 + 1e-3)));
+}
+
+#[test]
+fn polygon_winding_number() {
+    let square = unit_square();
+    let inside = Point::new(0.5, 0.5);
+    assert_eq!(square.winding_number(inside), 1);
+    assert_eq!(square.as_cw().winding_number(inside), -1);
+    assert_eq!(square.winding_number(Point::new(2.0, 0.5)), 0);
+
+    // A square wound around twice
+    let mut twice = square.points().clone();
+    twice.extend(square.points().iter().copied());
+    let twice = Polygon::new(twice).unwrap();
+    assert_eq!(twice.winding_number(inside), 2);
+    assert!(twice.contains_point(inside));
+}
+
+#[test]
+fn polygon_centroid() {
+    let centroid = unit_square().centroid();
+    assert_abs_diff_eq!(centroid, Point::new(0.5, 0.5), epsilon = 1e-10);
+    let triangle = Polygon::from_flat(&[[0.0, 0.0], [3.0, 0.0], [0.0, 3.0]]).unwrap();
+    assert_abs_diff_eq!(triangle.centroid(), Point::new(1.0, 1.0), epsilon = 1e-10);
+    let centroid = triangle.as_cw().centroid();
+    assert_abs_diff_eq!(centroid, Point::new(1.0, 1.0), epsilon = 1e-10);
+
+    // Extra points along one edge move the mean of the points, but not the centroid
+    let square = Polygon::from_flat(&[
+        [0.0, 0.0],
+        [1.0, 0.0],
+        [2.0, 0.0],
+        [3.0, 0.0],
+        [4.0, 0.0],
+        [4.0, 4.0],
+        [0.0, 4.0],
+    ])
+    .unwrap();
+    assert_abs_diff_eq!(square.centroid(), Point::new(2.0, 2.0), epsilon = 1e-10);
+    let mean = Point::centroid(square.points().iter().copied()).unwrap();
+    assert!(mean.distance(square.centroid()).into_inner() > 0.5);
+}
+
+#[test]
+fn polygon_centroid_zero_area() {
+    // With no area, the centroid falls back to the mean of the points
+    let collinear =
+        Polygon::from_flat(&[[0.0, 0.0], [1.0, 1.0], [3.0, 3.0], [2.0, 2.0]]).unwrap();
+    assert_abs_diff_eq!(collinear.centroid(), Point::new(1.5, 1.5), epsilon = 1e-10);
+    let figure_eight =
+        Polygon::from_flat(&[[0.0, 0.0], [1.0, 1.0], [1.0, 0.0], [0.0, 1.0]]).unwrap();
+    assert_abs_diff_eq!(figure_eight.centroid(), Point::new(0.5, 0.5), epsilon = 1e-10);
+}
+
+#[test]
+fn polygon_recenter_offset() {
+    let far_away = square(1.0e6, -2.0e6, 3.0);
+    let (recentered, translation) = far_away.recenter();
+    assert_abs_diff_eq!(translation.dx.into_inner(), -1.0e6 - 1.5, epsilon = 1e-6);
+    assert_abs_diff_eq!(translation.dy.into_inner(), 2.0e6 - 1.5, epsilon = 1e-6);
+    assert_abs_diff_eq!(recentered.points()[0], Point::new(-1.5, -1.5), epsilon = 1e-9);
+
+    let offset = Finite::from_inner(0.25);
+    let near_origin = square(-1.5, -1.5, 3.0).offset(offset).unwrap();
+    let recentered = recentered.offset(offset).unwrap();
+    assert_eq!(recentered.points().len(), near_origin.points().len());
+    for (&a, &b) in recentered.points().iter().zip(near_origin.points()) {
+        assert_abs_diff_eq!(a, b, epsilon = 1e-9);
+    }
+}
+
+#[test]
+fn polygon_recenter_zero_area() {
+    let collinear =
+        Polygon::from_flat(&[[1.0, 1.0], [2.0, 2.0], [4.0, 4.0], [3.0, 3.0]]).unwrap();
+    let (recentered, translation) = collinear.recenter();
+    assert_abs_diff_eq!(translation.dx.into_inner(), -2.5, epsilon = 1e-10);
+    assert_abs_diff_eq!(translation.dy.into_inner(), -2.5, epsilon = 1e-10);
+    assert_abs_diff_eq!(recentered.points()[0], Point::new(-1.5, -1.5), epsilon = 1e-10);
+}
+
+#[test]
+fn path_sample_uniform() {
+    // Two units along the x axis, then a quarter turn left around (2, 2)
+    let line = Line::new(Point::new(0.0, 0.0), Point::new(2.0, 0.0)).unwrap();
+    let arc = Arc {
+        center: Point::new(2.0, 2.0),
+        radius: Finite::from_inner(2.0),
+        start_angle: Angle::new(1.5 * PI),
+        stop_diff: AngleDiff(Finite::from_inner(0.5 * PI)),
+    };
+    let path = Path::new(vec![PathSegment::Line(line), PathSegment::Arc(arc)]).unwrap();
+    let length = 2.0 + PI;
+    assert_abs_diff_eq!(path.length().into_inner(), length, epsilon = 1e-10);
+
+    let points = path.sample_uniform(9);
+    assert_eq!(points.len(), 9);
+    assert_abs_diff_eq!(points[0], Point::new(0.0, 0.0), epsilon = 1e-10);
+    assert_abs_diff_eq!(points[8], Point::new(4.0, 2.0), epsilon = 1e-10);
+    // Distance along the path to each point, which is on one segment or the other
+    for (index, point) in points.iter().enumerate() {
+        let distance = if point.y.into_inner().abs() < 1e-10 {
+            point.x.into_inner()
+        } else {
+            let radius = point.distance(arc.center).into_inner();
+            assert_abs_diff_eq!(radius, 2.0, epsilon = 1e-10);
+            let angle = (*point - arc.center).angle().radians().into_inner();
+            let turned = angle + 0.5 * PI;
+            2.0 + 2.0 * turned
+        };
+        assert_abs_diff_eq!(distance, index as f64 * length / 8.0, epsilon = 1e-10);
+    }
+
+    assert_eq!(path.sample_uniform(1), vec![Point::new(0.0, 0.0)]);
+    assert!(path.sample_uniform(0).is_empty());
+}
+
+#[test]
+fn path_rounded_rect() {
+    let rect = Rect {
+        min: Point::new(1.0, 2.0),
+        max: Point::new(5.0, 4.0),
+    };
+    let path = Path::rounded_rect(rect, Finite::from_inner(0.5)).unwrap();
+    assert!(path.is_closed());
+    let lines = path.segments().iter().filter(|s| matches!(s, PathSegment::Line(_)));
+    assert_eq!(lines.count(), 4);
+    let arcs: Vec<Arc<f64>> = path
+        .segments()
+        .iter()
+        .filter_map(|segment| match segment {
+            | PathSegment::Arc(arc) => Some(*arc),
+            | _ => None,
+        })
+        .collect();
+    assert_eq!(arcs.len(), 4);
+    for arc in &arcs {
+        assert_abs_diff_eq!(arc.radius.into_inner(), 0.5, epsilon = 1e-10);
+        assert_abs_diff_eq!(arc.stop_diff.radians().into_inner(), PI / 2.0, epsilon = 1e-10);
+    }
+    assert_abs_diff_eq!(arcs[0].center, Point::new(4.5, 2.5), epsilon = 1e-10);
+    assert_abs_diff_eq!(arcs[2].center, Point::new(1.5, 3.5), epsilon = 1e-10);
+
+    // The radius is clamped to half the height, leaving no straight left and right sides
+    let path = Path::rounded_rect(rect, Finite::from_inner(3.0)).unwrap();
+    assert!(path.is_closed());
+    assert_eq!(path.segments().len(), 6);
+    for segment in path.segments() {
+        if let PathSegment::Arc(arc) = segment {
+            assert_abs_diff_eq!(arc.radius.into_inner(), 1.0, epsilon = 1e-10);
+        }
+    }
+}
+
+#[test]
+fn prepared_polygon_contains() {
+    // A regular 100-gon, and a clockwise L shape which isn't convex
+    let hundred_gon = Polygon::new(
+        (0..100)
+            .map(|k| {
+                let angle = 2.0 * PI * (k as f64 + 0.5) / 100.0;
+                Point::new(3.0 + 2.0 * angle.cos(), -1.0 + 2.0 * angle.sin())
+            })
+            .collect(),
+    )
+    .unwrap();
+    let clockwise_l = l_shape().as_cw();
+    assert!(hundred_gon.is_convex());
+    assert!(!clockwise_l.is_convex());
+
+    for polygon in [hundred_gon, clockwise_l] {
+        let prepared = PreparedPolygon::new(&polygon);
+        assert_eq!(prepared.is_convex(), polygon.is_convex());
+        // A grid offset from the polygons' corners and edges
+        for i in -20..=20 {
+            for j in -20..=20 {
+                let point = Point::new(3.0 + i as f64 * 0.1367, -1.0 + j as f64 * 0.1367);
+                assert_eq!(prepared.contains(point), polygon.contains(point), "{}", point);
+            }
+        }
+    }
+}
+
+#[test]
+fn polyline_polygon_into_segments() {
+    let points = vec![
+        Point::new(0.0, 0.0),
+        Point::new(2.0, 0.0),
+        Point::new(2.0, 1.0),
+        Point::new(0.5, 1.5),
+    ];
+    let polyline = Polyline::new(points.clone()).unwrap();
+    let segments = polyline.to_segments();
+    assert_eq!(segments.len(), 3);
+    assert_abs_diff_eq!(segments[2].stop(), points[3], epsilon = 1e-10);
+    assert_eq!(polyline.into_segments().len(), 3);
+
+    let polygon = Polygon::new(points.clone()).unwrap();
+    let segments = polygon.to_segments();
+    assert_eq!(segments.len(), 4);
+    assert_abs_diff_eq!(segments[3].start(), points[3], epsilon = 1e-10);
+    assert_abs_diff_eq!(segments[3].stop(), points[0], epsilon = 1e-10);
+    assert_eq!(polygon.into_segments().len(), 4);
+}
+
+// Turns left, so a positive offset is away from the center of the arc at the corner
+const LEFT_TURN: [(f64, f64); 3] = [(0.0, 0.0), (4.0, 0.0), (4.0, 4.0)];
+const RIGHT_TURN: [(f64, f64); 3] = [(0.0, 0.0), (4.0, 0.0), (4.0, -4.0)];
+
+#[test]
+fn polyarc_segments() {
+    use PathSegment::{Arc, Line};
+    let is_line = |segment: &PathSegment<f64>| matches!(segment, Line(_));
+    let points = [(0.0, 0.0), (2.0, 0.0), (2.0, 2.0)];
+
+    let sharp: Vec<_> = curved_polyline(&points, 0.0).iter_segments().collect();
+    assert_eq!(sharp.len(), 2);
+    assert!(sharp.iter().all(is_line));
+
+    // The arc takes the place of part of the line on either side of the corner
+    let curved: Vec<_> = curved_polyline(&points, 1.0).iter_segments().collect();
+    assert_eq!(curved.len(), 3);
+    match curved[..] {
+        | [Line(first), Arc(arc), Line(last)] => {
+            assert_abs_diff_eq!(first.stop(), Point::new(1.0, 0.0), epsilon = 1e-10);
+            assert_abs_diff_eq!(arc.start(), Point::new(1.0, 0.0), epsilon = 1e-10);
+            assert_abs_diff_eq!(arc.stop(), Point::new(2.0, 1.0), epsilon = 1e-10);
+            assert_abs_diff_eq!(last.start(), Point::new(2.0, 1.0), epsilon = 1e-10);
+        }
+        | _ => panic!("Expected line, arc, line but got {:?}", curved),
+    }
+
+    // Lines used up by the arcs at both ends are left out
+    let points = [(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (4.0, 2.0)];
+    let segments: Vec<_> = curved_polyline(&points, 1.0).iter_segments().collect();
+    assert_eq!(segments.iter().filter(|segment| is_line(segment)).count(), 2);
+    assert_eq!(segments.len(), 4);
+}
+
+#[test]
+fn polycurve_segments() {
+    let triangle = Polygon::from_flat(&[[0.0, 0.0], [6.0, 0.0], [3.0, 5.0]]).unwrap();
+    let polycurve = triangle.curve(Finite::from_inner(1.0));
+    let segments: Vec<_> = polycurve.iter_segments().collect();
+    assert_eq!(segments.len(), 6);
+    for (index, segment) in segments.iter().enumerate() {
+        let is_line = matches!(segment, PathSegment::Line(_));
+        assert_eq!(is_line, index % 2 == 0);
+    }
+    // Each segment starts where the one before it stops, around to the first
+    for (index, segment) in segments.iter().enumerate() {
+        let next = segments[(index + 1) % segments.len()];
+        assert_abs_diff_eq!(segment.stop(), next.start(), epsilon = 1e-10);
+    }
+
+    // Corners without curves join lines directly
+    let square = square(0.0, 0.0, 2.0).curve(Finite::from_inner(0.0));
+    let sharp: Vec<_> = square.iter_segments().collect();
+    assert_eq!(sharp.len(), 4);
+    assert!(sharp.iter().all(|segment| matches!(segment, PathSegment::Line(_))));
+
+    // Back to the segments of the path it came from
+    let polycurve = Polycurve::from_path(&rounded_rectangle_path()).unwrap();
+    assert_eq!(polycurve.iter_segments().count(), 8);
+}
+
+#[test]
+fn polyarc_offset_outward_grows_arc() {
+    let offset = curved_polyline(&LEFT_TURN, 1.0).offset(Finite::from_inner(1.0));
+    assert_polyarc_eq(&offset, &[(0.0, -1.0), (5.0, -1.0), (5.0, 4.0)], &[2.0]);
+}
+
+#[test]
+fn polyarc_offset_inward_shrinks_arc() {
+    let offset = curved_polyline(&LEFT_TURN, 1.0).offset(Finite::from_inner(-0.5));
+    assert_polyarc_eq(&offset, &[(0.0, 0.5), (3.5, 0.5), (3.5, 4.0)], &[0.5]);
+}
+
+#[test]
+fn polyarc_offset_arc_collapses_exactly() {
+    let offset = curved_polyline(&LEFT_TURN, 1.0).offset(Finite::from_inner(-1.0));
+    assert_polyarc_eq(&offset, &[(0.0, 1.0), (3.0, 1.0), (3.0, 4.0)], &[0.0]);
+}
+
+#[test]
+fn polyarc_offset_arc_collapses_to_sharp_corner() {
+    let offset = curved_polyline(&LEFT_TURN, 1.0).offset(Finite::from_inner(-1.5));
+    assert_polyarc_eq(&offset, &[(0.0, 1.5), (2.5, 1.5), (2.5, 4.0)], &[0.0]);
+}
+
+#[test]
+fn polyarc_offset_right_turn() {
+    let polyarc = curved_polyline(&RIGHT_TURN, 1.0);
+    let offset = polyarc.clone().offset(Finite::from_inner(0.5));
+    assert_polyarc_eq(&offset, &[(0.0, -0.5), (3.5, -0.5), (3.5, -4.0)], &[0.5]);
+    let offset = polyarc.offset(Finite::from_inner(-2.0));
+    assert_polyarc_eq(&offset, &[(0.0, 2.0), (6.0, 2.0), (6.0, -4.0)], &[3.0]);
+}
+
+#[test]
+fn polyarc_offset_sharp_corner_stays_sharp() {
+    let offset = curved_polyline(&LEFT_TURN, 0.0).offset(Finite::from_inner(1.0));
+    assert_polyarc_eq(&offset, &[(0.0, -1.0), (5.0, -1.0), (5.0, 4.0)], &[0.0]);
+}
+
+#[test]
+fn polyarc_offset_shallow_turn() {
+    // Turning 60deg, the curve size changes by tan(30deg) for each unit of offset
+    let corner = (4.0 + 2.0 * (PI / 3.0).cos(), 2.0 * (PI / 3.0).sin());
+    let polyarc = curved_polyline(&[(0.0, 0.0), (4.0, 0.0), corner], 1.0);
+    let offset = polyarc.offset(Finite::from_inner(1.0));
+    let curve_sizes = offset.curve_sizes();
+    assert_abs_diff_eq!(curve_sizes[0].into_inner(), 1.0 + (PI / 6.0).tan(), epsilon = 1e-10);
+    assert_abs_diff_eq!(offset.polyline().points()[1].y.into_inner(), -1.0, epsilon = 1e-10);
+}
+
+#[test]
+fn polyarc_offset_mixed_turns() {
+    let polyarc = curved_polyline(&[(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (4.0, 2.0)], 0.5);
+    let offset = polyarc.offset(Finite::from_inner(0.25));
+    assert_polyarc_eq(
+        &offset,
+        &[(0.0, -0.25), (2.25, -0.25), (2.25, 1.75), (4.0, 1.75)],
+        &[0.75, 0.25],
+    );
+}
+
+#[test]
+fn polyarc_offset_drops_vanishing_line() {
+    // Two turns of 45deg with a short line between them, which an inward offset reverses
+    let diagonal = 0.5 / 2.0_f64.sqrt();
+    let polyarc = curved_polyline(
+        &[(0.0, 0.0), (4.0, 0.0), (4.0 + diagonal, diagonal), (4.0 + diagonal, 4.0)],
+        0.2,
+    );
+    assert_eq!(polyarc.curve_sizes().len(), 2);
+    let offset = polyarc.offset(Finite::from_inner(-1.0));
+    assert_polyarc_eq(
+        &offset,
+        &[(0.0, 1.0), (3.0 + diagonal, 1.0), (3.0 + diagonal, 4.0)],
+        &[0.0],
+    );
+}
+
+#[test]
+fn polyarc_offset_there_and_back() {
+    let polyarc = curved_polyline(&[(0.0, 0.0), (3.0, 1.0), (4.0, 4.0), (1.0, 5.0)], 0.6);
+    let there = polyarc.clone().offset(Finite::from_inner(0.3));
+    let back = there.offset(Finite::from_inner(-0.3));
+    let points: Vec<(f64, f64)> = polyarc
+        .polyline()
+        .points()
+        .iter()
+        .map(|point| (point.x.into_inner(), point.y.into_inner()))
+        .collect();
+    let curve_sizes: Vec<f64> =
+        polyarc.curve_sizes().iter().map(|size| size.into_inner()).collect();
+    assert_polyarc_eq(&back, &points, &curve_sizes);
+
+    let unchanged = polyarc.clone().offset(Finite::from_inner(0.0));
+    assert_polyarc_eq(&unchanged, &points, &curve_sizes);
+}
+
+#[test]
+fn polyline_offset_drops_zero_length_line() {
+    // Offset inwards by 1, the middle line shrinks to nothing at (3, 1), where the lines
+    // either side of it already meet
+    let points = vec![
+        Point::new(0.0, 0.0),
+        Point::new(4.0, 0.0),
+        Point::new(4.0, 3.0),
+        Point::new(0.0, 0.0),
+    ];
+    let offset = Polyline::new(points).unwrap().offset(Finite::from_inner(-1.0));
+    let points = offset.points();
+    assert_eq!(points.len(), 3);
+    assert_abs_diff_eq!(points[0], Point::new(0.0, 1.0), epsilon = 1e-10);
+    assert_abs_diff_eq!(points[1], Point::new(3.0, 1.0), epsilon = 1e-10);
+    assert_abs_diff_eq!(points[2], Point::new(0.6, -0.8), epsilon = 1e-10);
+    assert_eq!(offset.iter_segments().count(), 2);
+}
+
+#[test]
+fn polycurve_offset_inset_until_corners_collapse() {
+    let polycurve = square(0.0, 0.0, 4.0).curve(Finite::from_inner(1.0));
+    let offset = polycurve.clone().offset(Finite::from_inner(-0.5));
+    assert_polycurve_eq(
+        &offset,
+        &[(0.5, 0.5), (3.5, 0.5), (3.5, 3.5), (0.5, 3.5)],
+        &[0.5, 0.5, 0.5, 0.5],
+    );
+    let offset = polycurve.offset(Finite::from_inner(-1.5));
+    assert_polycurve_eq(
+        &offset,
+        &[(1.5, 1.5), (2.5, 1.5), (2.5, 2.5), (1.5, 2.5)],
+        &[0.0, 0.0, 0.0, 0.0],
+    );
+}
+
+#[test]
+fn polycurve_offset_mixed_corners() {
+    // Outset, the arcs at the convex corners grow while the one at the concave corner
+    // shrinks
+    let polycurve = l_shape().curve(Finite::from_inner(0.25));
+    let offset = polycurve.offset(Finite::from_inner(0.1));
+    assert_polycurve_eq(
+        &offset,
+        &[(-0.1, -0.1), (2.1, -0.1), (2.1, 1.1), (1.1, 1.1), (1.1, 2.1), (-0.1, 2.1)],
+        &[0.35, 0.35, 0.35, 0.15, 0.35, 0.35],
+    );
+}
+
+#[test]
+fn polycurve_offset_from_path() {
+    // Corners of radius 0.5 on a 4 by 2 rectangle, so an inset of 0.5 leaves them sharp
+    let polycurve = Polycurve::from_path(&rounded_rectangle_path()).unwrap();
+    let offset = polycurve.offset(Finite::from_inner(-0.5));
+    assert_polycurve_eq(
+        &offset,
+        &[(0.5, 0.5), (3.5, 0.5), (3.5, 1.5), (0.5, 1.5)],
+        &[0.0, 0.0, 0.0, 0.0],
+    );
+}
+
+#[test]
+fn polygon_to_rings_figure_eight() {
+    // Crosses itself at (1, 1)
+    let figure_eight = Polygon::new(vec![
+        Point::new(0.0, 0.0),
+        Point::new(2.0, 2.0),
+        Point::new(2.0, 0.0),
+        Point::new(0.0, 2.0),
+    ])
+    .unwrap();
+    let rings = figure_eight.to_rings().unwrap();
+    assert_eq!(rings.len(), 2);
+    for ring in &rings {
+        assert!(ring.holes.is_empty());
+        assert_eq!(ring.outer.points().len(), 3);
+        assert_eq!(ring.outer.orientation(), Direction::Counterclockwise);
+        assert_area_eq(&ring.outer, 1.0, 1e-10);
+        let crossing = Point::new(1.0, 1.0);
+        assert!(ring.outer.points().iter().any(|&p| p.distance(crossing) < 1e-10));
+    }
+    let outers: Vec<Polygon<f64>> = rings.into_iter().map(|ring| ring.outer).collect();
+    assert_polygons_cover_area(&outers, 2.0, 1e-10);
+
+    // Small polygons keep their loops, however little area they have
+    let tiny = |x: f64, y: f64| Point::new(x * 1e-5, y * 1e-5);
+    let tiny_figure_eight =
+        Polygon::new(vec![tiny(0.0, 0.0), tiny(2.0, 2.0), tiny(2.0, 0.0), tiny(0.0, 2.0)])
+            .unwrap();
+    let rings = tiny_figure_eight.to_rings().unwrap();
+    assert_eq!(rings.len(), 2);
+    for ring in &rings {
+        assert_area_eq(&ring.outer, 1e-10, 1e-20);
+    }
+}
+
+#[test]
+fn polygon_to_rings_with_hole() {
+    // A square, with a diamond-shaped hole reached through the point (2, 0)
+    let loop_with_hole = Polygon::new(vec![
+        Point::new(0.0, 0.0),
+        Point::new(2.0, 0.0),
+        Point::new(1.0, 1.0),
+        Point::new(2.0, 2.0),
+        Point::new(3.0, 1.0),
+        Point::new(2.0, 0.0),
+        Point::new(4.0, 0.0),
+        Point::new(4.0, 4.0),
+        Point::new(0.0, 4.0),
+    ])
+    .unwrap();
+    let rings = loop_with_hole.to_rings().unwrap();
+    assert_eq!(rings.len(), 1);
+    assert_area_eq(&rings[0].outer, 16.0, 1e-10);
+    assert_eq!(rings[0].holes.len(), 1);
+    assert_area_eq(&rings[0].holes[0], 2.0, 1e-10);
+    assert_eq!(rings[0].holes[0].orientation(), Direction::Clockwise);
+
+    // A simple polygon is its own ring
+    let rings = l_shape().to_rings().unwrap();
+    assert_eq!(rings.len(), 1);
+    assert_eq!(rings[0].outer.points().len(), 6);
+    assert!(rings[0].holes.is_empty());
 }
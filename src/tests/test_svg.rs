@@ -1,10 +1,18 @@
 use std::f64::consts::PI;
 
+use decorum::Finite;
+use svg::node::element::tag::Type;
+use svg::parser::Event;
 
 use crate::geometry::arc::Arc;
 use crate::geometry::*;
 use crate::geometry::line::Line;
-use crate::to_svg::{to_document, CoordinateTransform, ToSvg};
+use crate::geometry::poly::{Curved, Polygon, Polyline};
+use crate::tests::{assert_svg_path_eq, svg_path_tokens};
+use crate::to_svg::{
+    to_document, to_document_with_options, CoordinateTransform, DocumentOptions,
+    FillStyling, LineStyling, SvgStreamWriter, ToSvg,
+};
 
 #[test]
 fn line_to_svg() {
@@ -12,6 +20,7 @@ fn line_to_svg() {
     let end_point: Point<f64> = Point::new(5.0, 3.0);
     let line = Line::new(start_point, end_point).unwrap();
     let node = line.to_svg(None);
+    assert_svg_path_eq(&node, "M1,1 L5,3");
     let output_path = "test_line.svg";
     let transform = CoordinateTransform {
         upper_left: Point::<f64>::new(10.0, 10.0),
@@ -31,6 +40,8 @@ fn arc_to_svg() {
     let arc = Arc::new(start_point, stop_point, angle).unwrap();
 
     let node = arc.to_svg(None);
+    let radius = 50.0_f64.sqrt();
+    assert_svg_path_eq(&node, &format!("M1,1 A{},{} 0 0,0 5,3", radius, radius));
     let output_path = "test_arc.svg";
     let transform = CoordinateTransform {
         upper_left: Point::<f64>::new(10.0, 10.0),
@@ -41,3 +52,268 @@ fn arc_to_svg() {
     svg::save(&output_path, &document)
         .expect(&format!("Unable to write to file {}", &output_path));
 }
+
+#[test]
+fn large_arc_to_svg() {
+    // Three quarters of a circle, counterclockwise from (1, 0) to (0, -1)
+    let arc = Arc {
+        center: Point::new(0.0, 0.0),
+        radius: Finite::from_inner(1.0),
+        start_angle: Angle::new(0.0),
+        stop_diff: AngleDiff(Finite::from_inner(1.5 * PI)),
+    };
+    let node = arc.to_svg(None);
+    assert_svg_path_eq(&node, "M1,0 A1,1 0 1,1 0,-1");
+
+    // The same points the short way round, clockwise
+    let arc = Arc {
+        stop_diff: AngleDiff(Finite::from_inner(-0.5 * PI)),
+        ..arc
+    };
+    assert_svg_path_eq(&arc.to_svg(None), "M1,0 A1,1 0 0,0 0,-1");
+}
+
+#[test]
+fn arc_to_svg_debug_overlay() {
+    // A quarter circle counterclockwise about (2, 1)
+    let center: Point<f64> = Point::new(2.0, 1.0);
+    let start = Point::new(4.0, 1.0);
+    let arc = Arc::from_center(center, start, Point::new(2.0, 3.0)).unwrap();
+
+    let style = LineStyling {
+        debug_overlay: true,
+    };
+    let node = arc.to_svg(Some(style));
+    let content = node.to_string();
+    let (paths, circles) = svg_tags(&content);
+    // The arc, the radius to the start, and the sweep arrowhead
+    assert_eq!(paths.len(), 3);
+    assert_eq!(circles, vec![(2.0, 1.0)]);
+    assert!(paths[0].contains('A'));
+    assert_eq!(paths[1], "M2,1 L4,1");
+
+    // Without the overlay there's just the arc
+    let (paths, circles) = svg_tags(&arc.to_svg(None).to_string());
+    assert_eq!(paths.len(), 1);
+    assert!(circles.is_empty());
+}
+
+#[test]
+fn line_to_svg_debug_overlay() {
+    let line = Line::new(Point::new(0.0, 0.0), Point::new(10.0, 0.0)).unwrap();
+    let style = LineStyling {
+        debug_overlay: true,
+    };
+    let (paths, circles) = svg_tags(&line.to_svg(Some(style)).to_string());
+    // The line and the arrowhead at its stop
+    assert_eq!(paths.len(), 2);
+    assert_eq!(circles, vec![(0.0, 0.0)]);
+}
+
+#[test]
+fn polycurve_to_svg() {
+    // A square with every corner rounded
+    let polygon = Polygon::new(vec![
+        Point::new(0.0, 0.0),
+        Point::new(4.0, 0.0),
+        Point::new(4.0, 4.0),
+        Point::new(0.0, 4.0),
+    ])
+    .unwrap();
+    let polycurve = polygon.curve(Finite::from_inner(1.0));
+
+    let fill = FillStyling {
+        color: String::from("#4080C0"),
+        opacity: 0.5,
+    };
+    let node = polycurve.to_svg((None, Some(fill)));
+    let (paths, _) = svg_tags(&node.to_string());
+    let d = &paths[0];
+    assert!(d.starts_with('M'));
+    assert!(d.ends_with('Z'));
+    assert_eq!(d.matches('A').count(), 4);
+    assert_eq!(d.matches('L').count(), 4);
+    assert_svg_path_eq(
+        &node,
+        "M1,0 L3,0 A1,1 0 0,1 4,1 L4,3 A1,1 0 0,1 3,4 \
+         L1,4 A1,1 0 0,1 0,3 L0,1 A1,1 0 0,1 1,0 Z",
+    );
+    let output_path = "test_polycurve.svg";
+    let transform = CoordinateTransform {
+        upper_left: Point::<f64>::new(10.0, 10.0),
+        scale: Delta::<f64>::new(1.0, 1.0),
+        rotation: Angle::<f64>::new(0.0),
+    };
+    let document = to_document(node, transform);
+    svg::save(output_path, &document)
+        .unwrap_or_else(|_| panic!("Unable to write to file {}", output_path));
+}
+
+#[test]
+fn document_options() {
+    let line = Line::new(Point::new(1.0, 1.0), Point::new(5.0, 3.0)).unwrap();
+    let transform = CoordinateTransform {
+        upper_left: Point::<f64>::new(0.0, 0.0),
+        scale: Delta::<f64>::new(1.0, 1.0),
+        rotation: Angle::<f64>::new(0.0),
+    };
+    let options = DocumentOptions {
+        preserve_aspect_ratio: Some(String::from("xMidYMid meet")),
+        background: Some(FillStyling {
+            color: String::from("white"),
+            opacity: 1.0,
+        }),
+    };
+    let document = to_document_with_options(line.to_svg(None), transform, options);
+    let content = document.to_string();
+    let mut background = None;
+    let mut preserve_aspect_ratio = None;
+    for event in svg::read(&content).unwrap() {
+        match event {
+            | Event::Tag("svg", Type::Start, attributes) => {
+                preserve_aspect_ratio = attributes.get("preserveAspectRatio").cloned();
+            }
+            | Event::Tag("rect", _, attributes) => {
+                // Behind the line, so before it
+                assert!(background.is_none());
+                background = Some(attributes);
+            }
+            | Event::Tag("path", _, _) => assert!(background.is_some()),
+            | _ => {}
+        }
+    }
+    assert_eq!(preserve_aspect_ratio.unwrap().to_string(), "xMidYMid meet");
+    let background = background.unwrap();
+    assert_eq!(background["fill"].to_string(), "white");
+    assert_eq!(background["width"].to_string(), "10");
+
+    // Neither by default
+    let document = to_document(line.to_svg(None), transform);
+    let content = document.to_string();
+    assert!(!content.contains("preserveAspectRatio"));
+    assert!(!content.contains("<rect"));
+}
+
+// The path data of each path, and the center of each circle.
+fn svg_tags(content: &str) -> (Vec<String>, Vec<(f64, f64)>) {
+    let mut paths = Vec::new();
+    let mut circles = Vec::new();
+    for event in svg::read(content).unwrap() {
+        match event {
+            | Event::Tag("path", _, attributes) => {
+                paths.push(attributes["d"].to_string());
+            }
+            | Event::Tag("circle", _, attributes) => {
+                let cx = attributes["cx"].parse().unwrap();
+                let cy = attributes["cy"].parse().unwrap();
+                circles.push((cx, cy));
+            }
+            | _ => {}
+        }
+    }
+    (paths, circles)
+}
+
+#[test]
+fn polyarc_to_svg() {
+    // A left turn then a right turn, each rounded by a quarter circle
+    let points = vec![
+        Point::new(0.0, 0.0),
+        Point::new(4.0, 0.0),
+        Point::new(4.0, 4.0),
+        Point::new(8.0, 4.0),
+    ];
+    let polyarc = Polyline::new(points).unwrap().curve(Finite::from_inner(1.0));
+
+    let node = polyarc.to_svg(LineStyling::default());
+    assert_svg_path_eq(&node, "M0,0 L3,0 A1,1 0 0,1 4,1 L4,3 A1,1 0 0,0 5,4 L8,4");
+    let output_path = "test_polyarc.svg";
+    let transform = CoordinateTransform {
+        upper_left: Point::<f64>::new(10.0, 10.0),
+        scale: Delta::<f64>::new(1.0, 1.0),
+        rotation: Angle::<f64>::new(0.0),
+    };
+    let document = to_document(node, transform);
+    svg::save(output_path, &document)
+        .unwrap_or_else(|_| panic!("Unable to write to file {}", output_path));
+}
+
+#[test]
+fn stream_lines_to_svg() {
+    let mut writer = SvgStreamWriter::begin(Vec::new(), (0.0, 0.0, 1000.0, 10.0)).unwrap();
+    for i in 0..1000 {
+        let x = i as f64;
+        let line = Line::new(Point::new(x, 0.0), Point::new(x + 0.5, 10.0)).unwrap();
+        writer.write_shape(&line, None).unwrap();
+    }
+    let buffer = writer.finish().unwrap();
+    let content = String::from_utf8(buffer).unwrap();
+
+    let mut n_paths = 0;
+    let mut n_svg_tags = 0;
+    for event in svg::read(&content).unwrap() {
+        match event {
+            Event::Error(error) => panic!("Invalid SVG: {:?}", error),
+            Event::Tag("path", _, attributes) => {
+                assert!(attributes.contains_key("d"));
+                n_paths += 1;
+            }
+            Event::Tag("svg", _, _) => n_svg_tags += 1,
+            _ => {}
+        }
+    }
+    assert_eq!(n_paths, 1000);
+    // Opening and closing tags
+    assert_eq!(n_svg_tags, 2);
+}
+
+#[test]
+fn arc_svg_params_round_trip() {
+    let center = Point::new(1.0, 2.0);
+    let radius = Finite::from_inner(2.0);
+    let arcs = [
+        // Small and large, each way round, and a semicircle
+        (0.0, PI / 3.0, Direction::Counterclockwise),
+        (0.0, PI / 3.0, Direction::Clockwise),
+        (PI / 4.0, 1.5 * PI, Direction::Counterclockwise),
+        (PI / 4.0, 1.5 * PI, Direction::Clockwise),
+        (PI / 2.0, 1.5 * PI, Direction::Counterclockwise),
+    ];
+    for (start_angle, stop_angle, direction) in arcs {
+        let arc = Arc::from_parametric(
+            center,
+            radius,
+            Angle::new(start_angle),
+            Angle::new(stop_angle),
+            direction,
+        )
+        .unwrap();
+        let (paths, _) = svg_tags(&arc.to_svg(None).to_string());
+        let tokens = svg_path_tokens(&paths[0]);
+        let number = |index: usize| tokens[index].parse::<f64>().unwrap();
+        let flag = |index: usize| tokens[index] == "1";
+        assert_eq!((tokens[0].as_str(), tokens[3].as_str()), ("M", "A"));
+        let parsed = Arc::from_svg_params(
+            Point::new(number(1), number(2)),
+            Finite::from_inner(number(4)),
+            flag(7),
+            flag(8),
+            Point::new(number(9), number(10)),
+        )
+        .unwrap();
+        assert_abs_diff_eq!(parsed.center, arc.center, epsilon = 1e-9);
+        assert_abs_diff_eq!(parsed.radius.into_inner(), 2.0, epsilon = 1e-9);
+        assert_abs_diff_eq!(parsed.start(), arc.start(), epsilon = 1e-9);
+        assert_abs_diff_eq!(
+            parsed.stop_diff.radians().into_inner(),
+            arc.stop_diff.radians().into_inner(),
+            epsilon = 1e-9
+        );
+    }
+
+    let start = Point::new(0.0, 0.0);
+    let end = Point::new(4.0, 0.0);
+    let one = Finite::from_inner(1.0);
+    assert!(Arc::from_svg_params(start, one, false, true, end).is_err());
+    assert!(Arc::from_svg_params(start, radius, false, true, start).is_err());
+}
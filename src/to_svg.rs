@@ -1,11 +1,16 @@
-use svg::node::element::{Group, Path};
+use std::io::{self, Write};
+
+use decorum::{Finite, Real};
+use num_traits::Signed;
+use svg::node::element::{Circle, Group, Path, Rectangle};
 use svg::node::Node;
 use svg::Document;
 
 use crate::geometry::arc::Arc;
 use crate::geometry::line::Line;
+use crate::geometry::path::PathSegment;
 use crate::geometry::poly::{Polyarc, Polycurve, Polygon, Polyline};
-use crate::geometry::{Angle, Delta, Point, Value};
+use crate::geometry::{Angle, AngleDiff, Angular, Delta, Point, Value};
 
 #[derive(Clone, Copy, Debug)]
 pub struct CoordinateTransform<T: Value> {
@@ -17,10 +22,28 @@ pub struct CoordinateTransform<T: Value> {
 pub trait ToSvg<T: Value> {
     type ElementStyling;
     fn to_svg(self: &Self, style: Self::ElementStyling) -> Group;
+
+    // The SVG fragment for this element on its own, for writing out without keeping the
+    // whole document in memory.
+    fn to_svg_string(&self, style: Self::ElementStyling) -> String {
+        self.to_svg(style).to_string()
+    }
 }
 
-pub struct LineStyling {/* todo */}
-pub struct FillStyling {/* todo */}
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LineStyling {
+    // Draw markers showing how the element was constructed, along with the element
+    // itself: where it starts and which way it goes, and for arcs, the center and
+    // radius.
+    pub debug_overlay: bool,
+}
+#[derive(Clone, Debug)]
+pub struct FillStyling {
+    // Any SVG paint, such as "#4080C0" or "red"
+    pub color: String,
+    // From 0 for transparent to 1 for opaque
+    pub opacity: f64,
+}
 //pub struct MarkerStyling {/* todo */}
 
 impl<T: Value> ToSvg<T> for Line<T> {
@@ -28,15 +51,16 @@ impl<T: Value> ToSvg<T> for Line<T> {
 
     fn to_svg(self: &Self, style: Self::ElementStyling) -> Group {
         let d_string = format!("M{} L{}", self.start(), self.stop()).to_string();
-        let mut path = Path::new().set("d", d_string).set("fill", "none");
-        if cfg!(debug_assertions) {
-            // debug color
-            path.assign("stroke", "#FF00FF");
-        } else {
-            path.assign("display", "none");
+        let path = Path::new().set("d", d_string).set("fill", "none");
+        if !style.is_some_and(|style| style.debug_overlay) {
+            return Group::new().add(debug_stroke(path));
         }
-        let group = Group::new().add(path);
-        return group;
+        let marker_size = marker_size(self.length());
+        let direction = (self.stop() - self.start()).angle();
+        Group::new()
+            .add(path.set("stroke", DEBUG_STROKE))
+            .add(point_marker(self.start(), marker_size))
+            .add(arrowhead(self.stop(), direction, marker_size))
     }
 }
 
@@ -52,14 +76,8 @@ impl<T: Value> ToSvg<T> for Polyline<T> {
         for point in points {
             d_string.push_str(&format!("L{} ", point));
         }
-        let mut path = Path::new().set("d", d_string).set("fill", "none");
-        if cfg!(debug_assertions) {
-            // debug color
-            path.assign("stroke", "#FF00FF");
-        } else {
-            path.assign("display", "none");
-        }
-        let group = Group::new().add(path);
+        let path = Path::new().set("d", d_string).set("fill", "none");
+        let group = Group::new().add(debug_stroke(path));
         return group;
     }
 }
@@ -78,13 +96,8 @@ impl<T: Value> ToSvg<T> for Polygon<T> {
             d_string.push_str(&format!("L{} ", point));
         }
         d_string.push_str("Z");
-        let mut path = Path::new().set("d", d_string).set("fill", "none");
-        if cfg!(debug_assertions) {
-            // debug color
-            path.assign("stroke", "#FF00FF");
-        } else {
-            path.assign("display", "none");
-        }
+        let (_, fill) = style;
+        let path = fill_path(Path::new().set("d", d_string), fill);
         let group = Group::new().add(path);
         return group;
     }
@@ -94,25 +107,22 @@ impl<T: Value> ToSvg<T> for Arc<T> {
     type ElementStyling = Option<LineStyling>;
 
     fn to_svg(self: &Self, style: Self::ElementStyling) -> Group {
-        let large_arc_flag = false;
         let d_string = format!(
-            "M{} A{},{} 0 {},{} {} ",
+            "M{} {}",
             self.start(),
-            self.radius,
-            self.radius,
-            large_arc_flag as usize,
-            self.sweep_flag() as usize,
-            self.stop()
+            segment_command(PathSegment::Arc(*self))
         );
-        let mut path = Path::new().set("d", d_string).set("fill", "none");
-        if cfg!(debug_assertions) {
-            // debug color
-            path.assign("stroke", "#FF00FF");
-        } else {
-            path.assign("display", "none");
+        let path = Path::new().set("d", d_string).set("fill", "none");
+        if !style.is_some_and(|style| style.debug_overlay) {
+            return Group::new().add(debug_stroke(path));
         }
-        let group = Group::new().add(path);
-        return group;
+        let marker_size = marker_size(self.radius);
+        let radius_line = format!("M{} L{}", self.center, self.start());
+        Group::new()
+            .add(path.set("stroke", DEBUG_STROKE))
+            .add(point_marker(self.center, marker_size))
+            .add(overlay_path(radius_line))
+            .add(arrowhead(self.stop(), self.tangent_angle_at_stop(), marker_size))
     }
 }
 
@@ -120,7 +130,15 @@ impl<T: Value> ToSvg<T> for Polyarc<T> {
     type ElementStyling = LineStyling;
 
     fn to_svg(self: &Self, style: Self::ElementStyling) -> Group {
-        todo!()
+        let path = self.to_path();
+        let segments = path.segments();
+        let mut d_string = String::with_capacity(48 * segments.len());
+        d_string.push_str(&format!("M{} ", path.start()));
+        for segment in segments {
+            d_string.push_str(&segment_command(*segment));
+        }
+        let path = Path::new().set("d", d_string).set("fill", "none");
+        Group::new().add(debug_stroke(path))
     }
 }
 
@@ -129,15 +147,159 @@ impl<T: Value> ToSvg<T> for Polycurve<T> {
     type ElementStyling = (Option<LineStyling>, Option<FillStyling>);
 
     fn to_svg(self: &Self, style: Self::ElementStyling) -> Group {
-        todo!()
+        let path = self.to_path();
+        let segments = path.segments();
+        let mut d_string = String::with_capacity(48 * segments.len());
+        d_string.push_str(&format!("M{} ", path.start()));
+        for segment in segments {
+            d_string.push_str(&segment_command(*segment));
+        }
+        d_string.push('Z');
+        let (_, fill) = style;
+        Group::new().add(fill_path(Path::new().set("d", d_string), fill))
     }
 }
 
+const DEBUG_STROKE: &str = "#FF00FF";
+const OVERLAY_STROKE: &str = "#00A0FF";
+
+// Elements are only drawn in debug builds, until styling is worked out.
+fn debug_stroke(mut path: Path) -> Path {
+    if cfg!(debug_assertions) {
+        path.assign("stroke", DEBUG_STROKE);
+    } else {
+        path.assign("display", "none");
+    }
+    path
+}
+
+// Filled shapes are drawn in any build, and unfilled ones only when debugging.
+fn fill_path(path: Path, fill: Option<FillStyling>) -> Path {
+    match fill {
+        | Some(fill) => path
+            .set("fill", fill.color)
+            .set("fill-opacity", fill.opacity),
+        | None => debug_stroke(path.set("fill", "none")),
+    }
+}
+
+fn overlay_path(d_string: String) -> Path {
+    Path::new()
+        .set("d", d_string)
+        .set("fill", "none")
+        .set("stroke", OVERLAY_STROKE)
+}
+
+// Markers are drawn in proportion to the element they're marking.
+fn marker_size<T: Value>(extent: Finite<T>) -> Finite<T> {
+    extent / Finite::<T>::from_inner(T::from_f64(10.0).unwrap())
+}
+
+fn point_marker<T: Value>(point: Point<T>, size: Finite<T>) -> Circle {
+    Circle::new()
+        .set("cx", point.x.into_inner().to_f64().unwrap())
+        .set("cy", point.y.into_inner().to_f64().unwrap())
+        .set("r", size.into_inner().to_f64().unwrap())
+        .set("fill", OVERLAY_STROKE)
+}
+
+// Two barbs swept back from the tip, pointing in the direction of travel.
+fn arrowhead<T: Value>(tip: Point<T>, direction: Angle<T>, size: Finite<T>) -> Path {
+    // Each barb is 150deg round from the direction of travel
+    let barb = AngleDiff(T::pi() - Finite::<T>::FRAC_PI_6);
+    let left = tip + Delta::magnitude_angle(size, direction + barb);
+    let right = tip + Delta::magnitude_angle(size, direction + -barb);
+    overlay_path(format!("M{} L{} L{}", left, tip, right))
+}
+
+// Path data continuing from the start of the segment to its stop.
+fn segment_command<T: Value>(segment: PathSegment<T>) -> String {
+    match segment {
+        | PathSegment::Line(line) => format!("L{} ", line.stop()),
+        | PathSegment::Arc(arc) => {
+            // Of the two arcs between the points, whether to draw the longer one
+            let large_arc_flag = arc.stop_diff.radians().abs() > T::pi();
+            format!(
+                "A{},{} 0 {},{} {} ",
+                arc.radius,
+                arc.radius,
+                large_arc_flag as usize,
+                arc.sweep_flag() as usize,
+                arc.stop()
+            )
+        }
+    }
+}
+
+// How the document as a whole is presented, apart from what's drawn in it.
+#[derive(Clone, Debug, Default)]
+pub struct DocumentOptions {
+    // How the viewBox is fit into the viewport, such as "xMidYMid meet" or "none" to
+    // stretch it. Left unset, viewers center it and scale it to fit.
+    pub preserve_aspect_ratio: Option<String>,
+    // Fill for a rectangle covering the whole viewBox, behind everything else.
+    pub background: Option<FillStyling>,
+}
+
 pub fn to_document<T: Value>(
     group: Group,
     transform: CoordinateTransform<T>,
 ) -> Document {
-    let viewbox = (0.0, 0.0, 10.0, 10.0);
-    let document = Document::new().set("viewBox", viewbox).add(group);
-    return document;
+    to_document_with_options(group, transform, DocumentOptions::default())
+}
+
+pub fn to_document_with_options<T: Value>(
+    group: Group,
+    transform: CoordinateTransform<T>,
+    options: DocumentOptions,
+) -> Document {
+    let (x, y, width, height) = (0.0, 0.0, 10.0, 10.0);
+    let mut document = Document::new().set("viewBox", (x, y, width, height));
+    if let Some(preserve_aspect_ratio) = options.preserve_aspect_ratio {
+        document.assign("preserveAspectRatio", preserve_aspect_ratio);
+    }
+    if let Some(background) = options.background {
+        let rect = Rectangle::new()
+            .set("x", x)
+            .set("y", y)
+            .set("width", width)
+            .set("height", height)
+            .set("fill", background.color)
+            .set("fill-opacity", background.opacity);
+        document.append(rect);
+    }
+    document.add(group)
+}
+
+// Writes out a document one element at a time, for drawings too large to build up as a
+// single Document before saving.
+pub struct SvgStreamWriter<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> SvgStreamWriter<W> {
+    pub fn begin(mut writer: W, viewbox: (f64, f64, f64, f64)) -> io::Result<Self> {
+        let (x, y, width, height) = viewbox;
+        write!(
+            writer,
+            r#"<svg viewBox="{} {} {} {}" xmlns="http://www.w3.org/2000/svg">"#,
+            x, y, width, height
+        )?;
+        Ok(Self { writer })
+    }
+
+    pub fn write_shape<T: Value, S: ToSvg<T>>(
+        &mut self,
+        shape: &S,
+        style: S::ElementStyling,
+    ) -> io::Result<()> {
+        write!(self.writer, "\n{}", shape.to_svg_string(style))
+    }
+
+    // Close the document, giving back the underlying writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        writeln!(self.writer, "\n</svg>")?;
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
 }